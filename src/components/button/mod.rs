@@ -114,6 +114,10 @@ pub struct ButtonProperties {
     #[prop_or_default]
     pub disabled: bool,
 
+    /// Disable the button, while keeping it focusable and able to show a tooltip.
+    #[prop_or_default]
+    pub aria_disabled: bool,
+
     #[prop_or_default]
     pub block: bool,
 
@@ -201,6 +205,9 @@ pub fn button(props: &ButtonProperties) -> Html {
     if props.loading {
         classes.push("pf-m-progress pf-m-in-progress")
     }
+    if props.aria_disabled {
+        classes.push("pf-m-aria-disabled");
+    }
 
     let label = use_memo(
         (props.label.clone(), props.icon, props.align),
@@ -233,7 +240,12 @@ pub fn button(props: &ButtonProperties) -> Html {
     let onclick = {
         let onclick = props.onclick.clone();
         let node_ref = node_ref.clone();
-        Callback::from(move |evt| {
+        let aria_disabled = props.aria_disabled;
+        Callback::from(move |evt: MouseEvent| {
+            if aria_disabled {
+                evt.prevent_default();
+                return;
+            }
             // Blur (loose focus) on the button element, to remove the focus after clicking
             if let Some(node) = node_ref.cast::<HtmlElement>() {
                 node.blur().ok();
@@ -256,6 +268,7 @@ pub fn button(props: &ButtonProperties) -> Html {
             form={props.form.clone()}
             formaction={props.formaction.clone()}
             aria-label={props.aria_label.clone()}
+            aria-disabled={props.aria_disabled.then_some("true")}
             aria-labelledby={&props.aria_labelledby}
             aria-haspopup={&props.aria_haspopup}
             aria-expanded={&props.aria_expanded}
@@ -1,5 +1,7 @@
 use crate::prelude::{
-    CalendarView, InputGroup, InputGroupItem, Popover, PopoverBody, PopoverContext, TextInput,
+    use_validation, CalendarView, InputGroup, InputGroupItem, InputState, Popover, PopoverBody,
+    PopoverContext, TextInput, ValidatingComponent, ValidatingComponentProperties,
+    ValidationContext,
 };
 use chrono::{Local, NaiveDate, Weekday};
 use yew::prelude::*;
@@ -24,6 +26,26 @@ pub struct DatePickerProperties {
     /// The day to start the week with
     #[prop_or(Weekday::Mon)]
     pub weekday_start: Weekday,
+    /// The validation state of the input
+    #[prop_or_default]
+    pub state: InputState,
+    /// Called when validation should occur, with `None` if the input isn't a valid date
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<Option<NaiveDate>>>,
+}
+
+impl ValidatingComponent for DatePicker {
+    type Value = Option<NaiveDate>;
+}
+
+impl ValidatingComponentProperties<Option<NaiveDate>> for DatePickerProperties {
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<Option<NaiveDate>>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
 }
 
 /// Date picker component
@@ -100,10 +122,13 @@ pub fn date_picker(props: &DatePickerProperties) -> Html {
         );
     }
 
+    use_validation(*value, props.onvalidate.clone());
+
     // The text input
     let input = html! (
         <TextInput
             onchange={input_change}
+            state={props.state}
             disabled={props.disabled}
             value={(*string_value).clone()}
             placeholder={props.placeholder.clone()}
@@ -28,6 +28,14 @@ pub struct DropdownProperties {
     #[prop_or_default]
     pub variant: MenuToggleVariant,
 
+    /// Render the toggle as split button, having a checkbox.
+    #[prop_or_default]
+    pub checkbox: Option<MenuToggleCheckbox>,
+
+    /// The validation state, shown as status of the toggle.
+    #[prop_or_default]
+    pub state: InputState,
+
     #[prop_or_default]
     pub position: Position,
 }
@@ -140,6 +148,8 @@ pub fn drop_down(props: &DropdownProperties) -> Html {
                     full_width={props.full_width}
                     aria_label={&props.aria_label}
                     variant={props.variant}
+                    checkbox={props.checkbox.clone()}
+                    state={props.state}
                     expanded={*expanded}
                     {ontoggle}
                 />
@@ -1,6 +1,13 @@
 //! The dynamic and composable [dual list selector](https://www.patternfly.org/components/dual-list-selector)
 
-use crate::{components::tooltip::TooltipProperties, icon::Icon};
+use crate::{
+    components::tooltip::TooltipProperties,
+    icon::Icon,
+    prelude::{
+        use_validation, InputState, ValidatingComponent, ValidatingComponentProperties,
+        ValidationContext,
+    },
+};
 use yew::prelude::*;
 
 mod control;
@@ -139,11 +146,35 @@ pub struct DualListSelectorProps<T: DualListSelectorItemRenderer> {
     #[prop_or_default]
     pub disabled: bool,
 
+    /// The validation state of the dual list selector, shown by the chosen pane.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called with the dynamically built chosen options, when validation should occur.
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<Vec<T>>>,
+
     /// Content to be rendered in the dual list selector. Panes & controls will not be built dynamically when children are provided.
     #[prop_or_default]
     pub children: Children,
 }
 
+impl<T: DualListSelectorItemRenderer> ValidatingComponent for DualListSelector<T> {
+    type Value = Vec<T>;
+}
+
+impl<T: DualListSelectorItemRenderer> ValidatingComponentProperties<Vec<T>>
+    for DualListSelectorProps<T>
+{
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<Vec<T>>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
+}
+
 /// The state of the dual list selector.
 /// Saves which options exist and which of those are selected
 /// for the "available" and "chosen" panels.
@@ -308,6 +339,8 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
         )
         .into()
     });
+    use_validation(state.chosen_options.clone(), props.onvalidate.clone());
+
     let control_option = |f: fn(&mut State<T>, MouseEvent)| {
         let state = state.clone();
         Callback::from(move |e| {
@@ -316,8 +349,9 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
             state.set(new_state);
         })
     };
+    let class = classes!["pf-v5-c-dual-list-selector", props.class.clone()];
     html! {
-      <div class={classes!["pf-v5-c-dual-list-selector", props.class.clone()]}>
+      <div {class}>
         if !props.children.is_empty() {
             { props.children.clone() }
         } else {
@@ -370,6 +404,7 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
                 is_chosen=true
                 title={props.chosen_options_title.clone()}
                 status={chosen_options_status}
+                state={props.state}
                 options={state.chosen_options.clone()}
                 onoptionselect={
                     let onoptionselect = onoptionselect.clone();
@@ -2,6 +2,8 @@
 
 use yew::prelude::*;
 
+use crate::prelude::{AsClasses, InputState};
+
 use super::{DualListSelectorItemRenderer, DualListSelectorList, DualListSelectorListWrapper};
 
 /// Acts as the container for a list of options that are either available or chosen,
@@ -31,6 +33,10 @@ pub struct DualListSelectorPaneProps<T: DualListSelectorItemRenderer> {
     #[prop_or_default]
     pub status: Option<AttrValue>,
 
+    /// The validation state, shown as an icon next to the status.
+    #[prop_or_default]
+    pub state: InputState,
+
     /// Flag indicating if this pane is the chosen pane.
     #[prop_or_default]
     pub is_chosen: bool,
@@ -64,12 +70,19 @@ pub fn pane<T: DualListSelectorItemRenderer>(props: &DualListSelectorPaneProps<T
             </div>
         },
     };
-    let status = match &props.status {
-        None => html! {},
-        Some(status) => html! {
+    let status = match (&props.status, props.state) {
+        (None, InputState::Default) => html! {},
+        (status, state) => html! {
             <div class="pf-v5-c-dual-list-selector__status">
+                if state != InputState::Default {
+                    <div class="pf-v5-c-helper-text">
+                        <div class={classes!("pf-v5-c-helper-text__item", state.as_classes())}>
+                            <span class="pf-v5-c-helper-text__item-icon">{ state.icon() }</span>
+                        </div>
+                    </div>
+                }
                 <div class="pf-v5-c-dual-list-selector__status-text" id="dual-list-selector-basic-available-pane-status">
-                    { status }
+                    { status.clone().unwrap_or_default() }
                 </div>
             </div>
         },
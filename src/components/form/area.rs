@@ -100,6 +100,9 @@ pub struct TextAreaProperties {
     #[prop_or_default]
     pub onvalidate: Callback<ValidationContext<String>>,
 
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+
     #[prop_or_default]
     pub r#ref: NodeRef,
 }
@@ -224,6 +227,7 @@ pub fn text_area(props: &TextAreaProperties) -> Html {
                 spellcheck={&props.spellcheck}
 
                 {oninput}
+                onblur={&props.onblur}
             />
             if props.state != InputState::Default {
                 <div class="pf-v5-c-form-control__utilities">
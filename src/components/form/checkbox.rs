@@ -1,5 +1,8 @@
 use crate::ouia;
-use crate::prelude::OuiaComponentType;
+use crate::prelude::{
+    use_validation, InputState, OuiaComponentType, ValidatingComponent,
+    ValidatingComponentProperties, ValidationContext,
+};
 use crate::utils::OuiaSafe;
 use crate::{core::OptionalHtml, hooks::id::use_prop_id, utils::Ouia};
 use web_sys::HtmlInputElement;
@@ -97,6 +100,14 @@ pub struct CheckboxProperties {
     #[prop_or_default]
     pub onchange: Callback<CheckboxState>,
 
+    /// The validation state of the checkbox.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called when validation should occur
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<CheckboxState>>,
+
     /// Label text of the checkbox.
     #[prop_or_default]
     pub label: OptionalHtml,
@@ -128,6 +139,20 @@ pub struct CheckboxProperties {
     pub ouia_safe: OuiaSafe,
 }
 
+impl ValidatingComponent for Checkbox {
+    type Value = CheckboxState;
+}
+
+impl ValidatingComponentProperties<CheckboxState> for CheckboxProperties {
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<CheckboxState>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
+}
+
 /// Checkbox component
 ///
 /// > A **checkbox** is used to select a single item or multiple items, typically to choose elements to perform an action or to reflect a binary setting.
@@ -149,6 +174,11 @@ pub fn checkbox(props: &CheckboxProperties) -> Html {
         outer_class.push("pf-m-standalone");
     }
 
+    // validation, also when the parent changes the state
+    use_validation(props.checked, props.onvalidate.clone());
+
+    let (outer_class, aria_invalid) = props.state.convert(outer_class);
+
     let node_ref = use_node_ref();
 
     {
@@ -162,13 +192,18 @@ pub fn checkbox(props: &CheckboxProperties) -> Html {
     }
 
     let onchange = use_callback(
-        (props.onchange.clone(), node_ref.clone()),
-        |_: Event, (onchange, node_ref)| {
+        (
+            props.onchange.clone(),
+            props.onvalidate.clone(),
+            node_ref.clone(),
+        ),
+        |_: Event, (onchange, onvalidate, node_ref)| {
             let checked = node_ref
                 .cast::<HtmlInputElement>()
                 .map(|input| input.checked().into())
                 .unwrap_or_default();
             onchange.emit(checked);
+            onvalidate.emit(checked.into());
         },
     );
 
@@ -195,7 +230,7 @@ pub fn checkbox(props: &CheckboxProperties) -> Html {
                 class={classes!["pf-v5-c-check__input", props.input_class.clone()]}
                 type="checkbox"
                 {onchange}
-                aria-invalid={props.valid.to_string()}
+                aria-invalid={(props.valid || aria_invalid).to_string()}
                 aria-label={props.aria_label.clone()}
                 disabled={props.disabled}
                 required={props.required}
@@ -1,7 +1,11 @@
 use crate::prelude::*;
-use std::{marker::PhantomData, rc::Rc};
+use futures::future::{AbortHandle, Abortable};
+use gloo_timers::callback::Timeout;
+use std::{any::Any, marker::PhantomData, rc::Rc};
 use uuid::Uuid;
+use wasm_bindgen_futures::spawn_local;
 use yew::{
+    context::ContextHandle,
     prelude::*,
     virtual_dom::{VChild, VNode},
 };
@@ -20,6 +24,9 @@ pub struct FormGroupProperties {
     pub label_icon: LabelIcon,
     #[prop_or_default]
     pub helper_text: Option<FormHelperText>,
+    /// Show that the value is being validated, instead of the helper text
+    #[prop_or_default]
+    pub validating: bool,
 }
 
 #[derive(Clone, Default, PartialEq)]
@@ -156,7 +163,9 @@ impl Component for FormGroup {
 
                 <div class="pf-v5-c-form__group-control">
                     { ctx.props().children.clone() }
-                    if let Some(text) = &ctx.props().helper_text {
+                    if ctx.props().validating {
+                        { FormGroupValidatingText }
+                    } else if let Some(text) = &ctx.props().helper_text {
                         { FormGroupHelpText(text) }
                     }
                 </div>
@@ -209,6 +218,31 @@ impl<'a> From<FormGroupHelpText<'a>> for VNode {
     }
 }
 
+/// The helper text of a [`FormGroup`] while validating.
+struct FormGroupValidatingText;
+
+impl From<FormGroupValidatingText> for VNode {
+    fn from(_: FormGroupValidatingText) -> Self {
+        html!(
+            <div
+                class="pf-v5-c-form__helper-text"
+                aria-live="polite"
+            >
+                <div class="pf-v5-c-helper-text">
+                    <div class="pf-v5-c-helper-text__item pf-m-dynamic">
+                        <span class="pf-v5-c-helper-text__item-icon">
+                            <Spinner size={SpinnerSize::Sm} aria_label="Validating" />
+                        </span>
+                        <span class="pf-v5-c-helper-text__item-text">
+                            { "Validating…" }
+                        </span>
+                    </div>
+                </div>
+            </div>
+        )
+    }
+}
+
 // with validation
 
 /// Properties for [`FormGroupValidated`]
@@ -225,7 +259,13 @@ where
     pub label_icon: LabelIcon,
     #[prop_or_default]
     pub required: bool,
+    /// The name of the field, providing its value to the validators of the [`Form`]
+    #[prop_or_default]
+    pub name: Option<String>,
     pub validator: Validator<C::Value, ValidationResult>,
+    /// Run after the `validator`, unless it reports an error
+    #[prop_or_default]
+    pub async_validator: AsyncValidator<C::Value, ValidationResult>,
 
     #[prop_or_default]
     pub onvalidated: Callback<ValidationResult>,
@@ -237,6 +277,10 @@ where
     C: ValidatingComponent,
 {
     Validate(ValidationContext<C::Value>),
+    /// The result of an asynchronous validation run
+    Validated(usize, ValidationResult),
+    /// The context of the form changed
+    FormChanged(ValidationFormContext),
 }
 
 impl<C> PartialEq for FormGroupValidatedProperties<C>
@@ -246,10 +290,55 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.required == other.required
             && self.label == other.label
+            && self.name == other.name
             && self.children == other.children
     }
 }
 
+/// A [`FormGroup`] validating its input component.
+///
+/// The component must implement [`ValidatingComponent`], which defines the type of the value
+/// being validated.
+///
+/// ## Properties
+///
+/// Defined by [`FormGroupValidatedProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+/// use chrono::NaiveDate;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let today = NaiveDate::from_ymd_opt(2024, 1, 1);
+///   let after_today = Validator::from(move |ctx: ValidationContext<Option<NaiveDate>>| {
+///     match ctx.value {
+///       Some(date) if Some(date) <= today => ValidationResult::error("Must be in the future"),
+///       _ => ValidationResult::ok(),
+///     }
+///   });
+///
+///   html!(
+///     <Form>
+///       <FormGroupValidated<NumberInput<u32>> label="Count" validator={validators::range(1..=10)}>
+///         <NumberInput<u32> value=0 />
+///       </FormGroupValidated<NumberInput<u32>>>
+///       <FormGroupValidated<DatePicker> label="Date" validator={validators::required().and(after_today)}>
+///         <DatePicker />
+///       </FormGroupValidated<DatePicker>>
+///       <FormGroupValidated<SimpleSelect<String>> label="Color" validator={validators::required()}>
+///         <SimpleSelect<String> entries={vec!["Red".to_string(), "Blue".to_string()]} />
+///       </FormGroupValidated<SimpleSelect<String>>>
+///       <FormGroupValidated<Checkbox> validator={validators::required()}>
+///         <Checkbox label="I accept the terms" />
+///       </FormGroupValidated<Checkbox>>
+///     </Form>
+///   )
+/// }
+/// ```
 pub struct FormGroupValidated<C>
 where
     C: BaseComponent,
@@ -258,41 +347,139 @@ where
 
     id: String,
     state: Option<ValidationResult>,
+
+    /// Counting validation runs, to discard stale results of asynchronous runs
+    run: usize,
+    /// The result of the synchronous validator, while the asynchronous one is pending
+    pending: Option<Option<ValidationResult>>,
+    timeout: Option<Timeout>,
+    /// The asynchronous validation in flight, aborted when dropped
+    validation: Option<AbortOnDrop>,
+
+    /// The result of the validators of the form
+    form_state: Option<ValidationResult>,
+    _form: Option<ContextHandle<ValidationFormContext>>,
+}
+
+/// Aborts an asynchronous validation when dropped.
+struct AbortOnDrop(AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
 }
 
 impl<C> Component for FormGroupValidated<C>
 where
     C: BaseComponent + ValidatingComponent,
     <C as BaseComponent>::Properties: ValidatingComponentProperties<C::Value> + Clone,
+    C::Value: Clone + 'static,
 {
     type Message = FormGroupValidatedMsg<C>;
     type Properties = FormGroupValidatedProperties<C>;
 
-    fn create(_: &Context<Self>) -> Self {
+    fn create(ctx: &Context<Self>) -> Self {
+        let (form_state, form) = match ctx
+            .link()
+            .context::<ValidationFormContext>(ctx.link().callback(Self::Message::FormChanged))
+        {
+            Some((form, handle)) => (Self::form_state(ctx, &form), Some(handle)),
+            None => (None, None),
+        };
+
         Self {
             _marker: Default::default(),
             id: Uuid::new_v4().to_string(),
             state: None,
+            run: 0,
+            pending: None,
+            timeout: None,
+            validation: None,
+            form_state,
+            _form: form,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Self::Message::Validate(value) => {
-                let state = ctx.props().validator.run(value);
-                if self.state != state {
-                    self.state = state;
-                    ctx.props()
-                        .onvalidated
-                        .emit(self.state.clone().unwrap_or_default());
-                    if let Some((validation_ctx, _)) = ctx
-                        .link()
-                        .context::<ValidationFormContext>(Callback::noop())
-                    {
-                        validation_ctx
-                            .push_state(GroupValidationResult(self.id.clone(), self.state.clone()));
+                if let (Some(name), Some((form, _))) = (
+                    &ctx.props().name,
+                    ctx.link()
+                        .context::<ValidationFormContext>(Callback::noop()),
+                ) {
+                    form.push_value(
+                        name.clone(),
+                        ValidationContext {
+                            value: Rc::new(value.value.clone()) as Rc<dyn Any>,
+                            initial: value.initial,
+                        },
+                    );
+                }
+
+                // cancel a pending run
+                self.run += 1;
+                self.timeout = None;
+                self.validation = None;
+
+                let async_validator = &ctx.props().async_validator;
+                if !async_validator.is_custom() {
+                    let state = ctx.props().validator.run(value);
+                    self.set_pending(ctx, None);
+                    self.set_state(ctx, state);
+                    return true;
+                }
+
+                let state = ctx.props().validator.run_ctx(value.clone());
+                if state
+                    .as_ref()
+                    .is_some_and(|state| state.state == InputState::Error)
+                {
+                    self.set_pending(ctx, None);
+                    self.set_state(ctx, state);
+                    return true;
+                }
+
+                let (run, link, validator) =
+                    (self.run, ctx.link().clone(), async_validator.clone());
+                let (handle, registration) = AbortHandle::new_pair();
+                let debounce = validator.get_debounce().as_millis().min(u32::MAX as u128) as u32;
+                self.timeout = Some(Timeout::new(debounce, move || {
+                    if let Some(validation) = validator.run(value) {
+                        spawn_local(async move {
+                            if let Ok(result) = Abortable::new(validation, registration).await {
+                                link.send_message(Self::Message::Validated(run, result));
+                            }
+                        });
                     }
+                }));
+                self.validation = Some(AbortOnDrop(handle));
+                // show the result of the new value, until the asynchronous one arrives
+                self.set_state(ctx, state.clone());
+                self.set_pending(ctx, Some(state));
+            }
+            Self::Message::Validated(run, result) => {
+                if run != self.run {
+                    // outdated
+                    return false;
+                }
+                self.timeout = None;
+                self.validation = None;
+                // report the most severe result
+                let state = match self.pending.take().flatten() {
+                    Some(state) => state.most_severe(result),
+                    None => result,
+                };
+                self.set_pending(ctx, None);
+                self.set_state(ctx, Some(state));
+            }
+            Self::Message::FormChanged(form) => {
+                let form_state = Self::form_state(ctx, &form);
+                if self.form_state == form_state {
+                    return false;
                 }
+                self.form_state = form_state;
             }
         }
         true
@@ -301,17 +488,24 @@ where
     fn view(&self, ctx: &Context<Self>) -> Html {
         let onvalidate = ctx.link().callback(|v| FormGroupValidatedMsg::Validate(v));
 
+        // the result of the form validators, if more severe
+        let state = match (self.state.clone(), self.form_state.clone()) {
+            (Some(state), Some(form_state)) => Some(state.most_severe(form_state)),
+            (state, form_state) => state.or(form_state),
+        };
+
         html!(
             <FormGroup
                 label={ctx.props().label.clone()}
                 label_icon={ctx.props().label_icon.clone()}
                 required={ctx.props().required}
-                helper_text={self.state.clone().and_then(|s|s.into())}
+                helper_text={state.clone().and_then(|s|s.into())}
+                validating={self.pending.is_some()}
             >
                 { for ctx.props().children.iter().map(|mut c|{
                     let props = Rc::make_mut(&mut c.props);
                     props.set_onvalidate(onvalidate.clone());
-                    props.set_input_state(self.state.as_ref().map(|s|s.state).unwrap_or_default());
+                    props.set_input_state(state.as_ref().map(|s|s.state).unwrap_or_default());
                     c
                 })}
             </FormGroup>
@@ -319,11 +513,69 @@ where
     }
 
     fn destroy(&mut self, ctx: &Context<Self>) {
+        self.timeout = None;
+        self.validation = None;
+
+        let props = ctx.props();
         if let Some((ctx, _)) = ctx
             .link()
             .context::<ValidationFormContext>(Callback::noop())
         {
             ctx.clear_state(self.id.clone());
+            if self.pending.is_some() {
+                ctx.push_pending(self.id.clone(), false);
+            }
+            if let Some(name) = &props.name {
+                ctx.clear_value(name.clone());
+            }
+        }
+    }
+}
+
+impl<C> FormGroupValidated<C>
+where
+    C: BaseComponent + ValidatingComponent,
+    <C as BaseComponent>::Properties: ValidatingComponentProperties<C::Value> + Clone,
+    C::Value: Clone + 'static,
+{
+    fn form_state(ctx: &Context<Self>, form: &ValidationFormContext) -> Option<ValidationResult> {
+        ctx.props()
+            .name
+            .as_deref()
+            .and_then(|name| form.form_result(name))
+            .cloned()
+    }
+
+    fn set_state(&mut self, ctx: &Context<Self>, state: Option<ValidationResult>) {
+        if self.state == state {
+            return;
+        }
+
+        self.state = state;
+        ctx.props()
+            .onvalidated
+            .emit(self.state.clone().unwrap_or_default());
+        if let Some((validation_ctx, _)) = ctx
+            .link()
+            .context::<ValidationFormContext>(Callback::noop())
+        {
+            validation_ctx.push_state(GroupValidationResult(self.id.clone(), self.state.clone()));
+        }
+    }
+
+    /// Start or stop waiting for an asynchronous run, keeping the result of the synchronous one.
+    fn set_pending(&mut self, ctx: &Context<Self>, pending: Option<Option<ValidationResult>>) {
+        let changed = self.pending.is_some() != pending.is_some();
+        self.pending = pending;
+        if !changed {
+            return;
+        }
+
+        if let Some((validation_ctx, _)) = ctx
+            .link()
+            .context::<ValidationFormContext>(Callback::noop())
+        {
+            validation_ctx.push_pending(self.id.clone(), self.pending.is_some());
         }
     }
 }
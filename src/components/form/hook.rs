@@ -0,0 +1,459 @@
+use super::{
+    CheckboxProperties, CheckboxState, FormSelectProperties, InputState, TextAreaProperties,
+    TextInputProperties,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+use yew::prelude::*;
+
+/// Get a field of a value, by its name.
+fn get_field<T>(value: &T, name: &str) -> Option<Value>
+where
+    T: Serialize,
+{
+    match serde_json::to_value(value).ok()? {
+        Value::Object(mut fields) => fields.remove(name),
+        _ => None,
+    }
+}
+
+/// Set a field of a value, by its name.
+///
+/// Returns `None` if the new field value doesn't fit the type.
+fn set_field<T>(value: &T, name: &str, field: Value) -> Option<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut value = serde_json::to_value(value).ok()?;
+    value.as_object_mut()?.insert(name.to_string(), field);
+    serde_json::from_value(value).ok()
+}
+
+/// The state of a single field of a form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldState {
+    /// The value differs from the initial value
+    pub dirty: bool,
+    /// The user interacted with the field
+    pub touched: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct FormState<T> {
+    initial: Rc<T>,
+    value: Rc<T>,
+    touched: BTreeSet<String>,
+}
+
+impl<T> FormState<T> {
+    fn new(initial: Rc<T>) -> Self {
+        Self {
+            value: initial.clone(),
+            initial,
+            touched: Default::default(),
+        }
+    }
+}
+
+enum FormAction<T> {
+    Set(String, Value),
+    Touch(String),
+    /// Reset to the initial value, or a new one
+    Reset(Option<T>),
+}
+
+impl<T> Reducible for FormState<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Action = FormAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            FormAction::Set(name, field) => match set_field(&*self.value, &name, field) {
+                Some(value) => Rc::new(Self {
+                    initial: self.initial.clone(),
+                    value: Rc::new(value),
+                    touched: self.touched.clone(),
+                }),
+                None => {
+                    log::warn!("Unable to set form field: {name}");
+                    self
+                }
+            },
+            FormAction::Touch(name) if self.touched.contains(&name) => self,
+            FormAction::Touch(name) => {
+                let mut touched = self.touched.clone();
+                touched.insert(name);
+                Rc::new(Self {
+                    initial: self.initial.clone(),
+                    value: self.value.clone(),
+                    touched,
+                })
+            }
+            FormAction::Reset(initial) => Rc::new(Self::new(
+                initial.map(Rc::new).unwrap_or_else(|| self.initial.clone()),
+            )),
+        }
+    }
+}
+
+/// The result of [`use_form`].
+pub struct UseForm<T>
+where
+    T: PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    state: UseReducerHandle<FormState<T>>,
+    validation: UseStateHandle<InputState>,
+}
+
+impl<T> Clone for UseForm<T>
+where
+    T: PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            validation: self.validation.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseForm<T>
+where
+    T: PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.validation == other.validation
+    }
+}
+
+impl<T> Debug for UseForm<T>
+where
+    T: PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UseForm")
+            .field("touched", &self.state.touched)
+            .field("validation", &*self.validation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> UseForm<T>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// The current value of the form.
+    pub fn value(&self) -> &T {
+        &self.state.value
+    }
+
+    /// The initial value of the form.
+    pub fn initial(&self) -> &T {
+        &self.state.initial
+    }
+
+    /// Get the current value of a field.
+    ///
+    /// Returns `None` if there is no such field, or it doesn't have the requested type.
+    pub fn get<V>(&self, name: &str) -> Option<V>
+    where
+        V: DeserializeOwned,
+    {
+        serde_json::from_value(get_field(self.value(), name)?).ok()
+    }
+
+    /// Create a callback, setting the value of a field.
+    pub fn setter<V>(&self, name: &str) -> Callback<V>
+    where
+        V: Serialize + 'static,
+    {
+        let (state, name) = (self.state.clone(), name.to_string());
+        Callback::from(move |value: V| match serde_json::to_value(value) {
+            Ok(value) => state.dispatch(FormAction::Set(name.clone(), value)),
+            Err(err) => log::warn!("Unable to serialize form field {name}: {err}"),
+        })
+    }
+
+    /// Mark a field as touched.
+    pub fn touch(&self, name: &str) {
+        self.state.dispatch(FormAction::Touch(name.to_string()));
+    }
+
+    /// Get the state of a field.
+    pub fn field(&self, name: &str) -> FieldState {
+        FieldState {
+            dirty: get_field(self.initial(), name) != get_field(self.value(), name),
+            touched: self.state.touched.contains(name),
+        }
+    }
+
+    /// Check if the value differs from the initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.state.value != self.state.initial
+    }
+
+    /// Check if any field was touched.
+    pub fn is_touched(&self) -> bool {
+        !self.state.touched.is_empty()
+    }
+
+    /// Reset the form to its initial value.
+    pub fn reset(&self) {
+        self.state.dispatch(FormAction::Reset(None));
+    }
+
+    /// Reset the form to a new initial value, e.g. after it got saved.
+    pub fn reset_to(&self, initial: T) {
+        self.state.dispatch(FormAction::Reset(Some(initial)));
+    }
+
+    /// The overall validation state, as reported by the [`super::Form`].
+    pub fn state(&self) -> InputState {
+        *self.validation
+    }
+
+    /// Check if the form is valid, which is the case unless a field has an error.
+    pub fn is_valid(&self) -> bool {
+        *self.validation != InputState::Error
+    }
+
+    /// The callback for the `onvalidated` property of the [`super::Form`].
+    pub fn onvalidated(&self) -> Callback<InputState> {
+        let validation = self.validation.clone();
+        Callback::from(move |state| validation.set(state))
+    }
+
+    /// The callback for the `onsubmit` property of the [`super::Form`].
+    ///
+    /// Calls `onsubmit` with the current value, unless the form has errors.
+    pub fn onsubmit(&self, onsubmit: Callback<T>) -> Callback<SubmitEvent> {
+        let (value, valid) = (self.state.value.clone(), self.is_valid());
+        Callback::from(move |evt: SubmitEvent| {
+            evt.prevent_default();
+            if valid {
+                onsubmit.emit((*value).clone());
+            }
+        })
+    }
+
+    /// Create a callback, marking a field as touched.
+    fn ontouch<E>(&self, name: &str) -> Callback<E> {
+        let (state, name) = (self.state.clone(), name.to_string());
+        Callback::from(move |_| state.dispatch(FormAction::Touch(name.clone())))
+    }
+
+    /// Create a callback, setting a field and marking it as touched.
+    fn onchange<V>(&self, name: &str) -> Callback<V>
+    where
+        V: Serialize + 'static,
+    {
+        let (setter, ontouch) = (self.setter(name), self.ontouch::<()>(name));
+        Callback::from(move |value| {
+            setter.emit(value);
+            ontouch.emit(());
+        })
+    }
+
+    /// Bind a [`super::TextInput`] to a text field.
+    ///
+    /// The field is touched when the input loses the focus.
+    pub fn text(&self, name: &str) -> TextInputProperties {
+        yew::props!(TextInputProperties {
+            name: AttrValue::from(name.to_string()),
+            value: self
+                .get::<Option<String>>(name)
+                .flatten()
+                .unwrap_or_default(),
+            onchange: self.setter(name),
+            onblur: self.ontouch(name),
+        })
+    }
+
+    /// Bind a [`super::TextArea`] to a text field.
+    ///
+    /// The field is touched when the text area loses the focus.
+    pub fn text_area(&self, name: &str) -> TextAreaProperties {
+        yew::props!(TextAreaProperties {
+            name: AttrValue::from(name.to_string()),
+            value: self
+                .get::<Option<String>>(name)
+                .flatten()
+                .unwrap_or_default(),
+            onchange: self.setter(name),
+            onblur: self.ontouch(name),
+        })
+    }
+
+    /// Bind a [`super::Checkbox`] to a boolean field.
+    pub fn checkbox(&self, name: &str) -> CheckboxProperties {
+        yew::props!(CheckboxProperties {
+            name: AttrValue::from(name.to_string()),
+            checked: CheckboxState::from(self.get::<bool>(name).unwrap_or_default()),
+            onchange: self.onchange::<bool>(name).reform(bool::from),
+        })
+    }
+
+    /// Bind a [`super::FormSelect`] to an optional field.
+    pub fn select<K>(&self, name: &str) -> FormSelectProperties<K>
+    where
+        K: Clone + PartialEq + Display + FromStr + Serialize + DeserializeOwned + 'static,
+    {
+        yew::props!(FormSelectProperties::<K> {
+            name: AttrValue::from(name.to_string()),
+            value: self.get::<Option<K>>(name).flatten(),
+            onchange: self.onchange(name),
+        })
+    }
+}
+
+/// Manage the state of a form, having a typed value.
+///
+/// The value must be (de)serializable using `serde`, as fields are bound by their name. Form
+/// controls are bound by spreading the properties provided by [`UseForm::text`],
+/// [`UseForm::text_area`], [`UseForm::checkbox`], or [`UseForm::select`]. Additional properties
+/// can be set as usual.
+///
+/// Each field tracks if it is dirty (differs from its initial value), or touched (the user
+/// interacted with it). Wiring up [`UseForm::onvalidated`] and [`UseForm::onsubmit`] with the
+/// [`super::Form`] hands over the value when the form gets submitted, unless it has errors.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+/// struct Account {
+///   name: String,
+///   description: String,
+///   role: Option<String>,
+///   active: bool,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let form = use_form(Account::default);
+///
+///   let onsave = Callback::from(|account: Account| log::info!("Save: {account:?}"));
+///   let onreset = {
+///     let form = form.clone();
+///     Callback::from(move |_| form.reset())
+///   };
+///
+///   let validator = Validator::from(|ctx: ValidationContext<String>| match ctx.value.is_empty() {
+///     true => ValidationResult::error("Must not be empty"),
+///     false => ValidationResult::ok(),
+///   });
+///
+///   html!(
+///     <Form onvalidated={form.onvalidated()} onsubmit={form.onsubmit(onsave)}>
+///       <FormGroupValidated<TextInput> label="Name" required=true {validator}>
+///         <TextInput ..form.text("name") />
+///       </FormGroupValidated<TextInput>>
+///       <FormGroup label="Description">
+///         <TextArea ..form.text_area("description") />
+///       </FormGroup>
+///       <FormGroup label="Role">
+///         <FormSelect<String> placeholder="Select a role" ..form.select("role")>
+///           <FormSelectOption<String> value="admin" />
+///           <FormSelectOption<String> value="user" />
+///         </FormSelect<String>>
+///       </FormGroup>
+///       <FormGroup>
+///         <Checkbox label="Active" ..form.checkbox("active") />
+///       </FormGroup>
+///       <ActionGroup>
+///         <Button variant={ButtonVariant::Primary} r#type={ButtonType::Submit} label="Save" disabled={!form.is_dirty()} />
+///         <Button variant={ButtonVariant::Link} label="Reset" onclick={onreset} />
+///       </ActionGroup>
+///     </Form>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_form<T, F>(initial: F) -> UseForm<T>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer_eq(|| FormState::new(Rc::new(initial())));
+    let validation = use_state_eq(InputState::default);
+
+    UseForm { state, validation }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Example {
+        name: String,
+        role: Option<String>,
+        active: bool,
+    }
+
+    fn state() -> Rc<FormState<Example>> {
+        Rc::new(FormState::new(Rc::new(Example::default())))
+    }
+
+    #[test]
+    fn test_get_field() {
+        let value = Example {
+            name: "foo".into(),
+            ..Default::default()
+        };
+        assert_eq!(get_field(&value, "name"), Some(Value::from("foo")));
+        assert_eq!(get_field(&value, "role"), Some(Value::Null));
+        assert_eq!(get_field(&value, "unknown"), None);
+    }
+
+    #[test]
+    fn test_set_field() {
+        let value = Example::default();
+        assert_eq!(
+            set_field(&value, "role", Value::from("admin")),
+            Some(Example {
+                role: Some("admin".into()),
+                ..Default::default()
+            })
+        );
+        // wrong type
+        assert_eq!(set_field(&value, "active", Value::from("yes")), None);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let state = state()
+            .reduce(FormAction::Set("name".into(), Value::from("foo")))
+            .reduce(FormAction::Touch("name".into()));
+        assert_eq!(state.value.name, "foo");
+        assert_eq!(state.initial.name, "");
+        assert!(state.touched.contains("name"));
+
+        // ignore invalid values
+        let state = state.reduce(FormAction::Set("active".into(), Value::from(1)));
+        assert!(!state.value.active);
+
+        let state = state.reduce(FormAction::Reset(None));
+        assert_eq!(*state, *self::state());
+    }
+
+    #[test]
+    fn test_reset_to() {
+        let saved = Example {
+            name: "foo".into(),
+            ..Default::default()
+        };
+        let state = state().reduce(FormAction::Reset(Some(saved.clone())));
+        assert_eq!(*state.initial, saved);
+        assert_eq!(*state.value, saved);
+    }
+}
@@ -2,6 +2,7 @@
 mod area;
 mod checkbox;
 mod group;
+mod hook;
 mod input;
 mod radio;
 mod section;
@@ -11,14 +12,19 @@ mod validation;
 pub use area::*;
 pub use checkbox::*;
 pub use group::*;
+pub use hook::*;
 pub use input::*;
 pub use radio::*;
 pub use section::*;
 pub use select::*;
-use std::collections::BTreeMap;
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
 pub use validation::*;
 
-use crate::prelude::{Alert, AlertType, AsClasses, Button, ExtendClasses, WithBreakpoints};
+use crate::prelude::{
+    Alert, AlertType, AsClasses, Button, ExtendClasses, ValidationContext, WithBreakpoints,
+};
 use yew::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -73,6 +79,17 @@ pub struct FormProperties {
     #[prop_or_default]
     pub validation_error_title: Option<String>,
 
+    /// Validators spanning several fields, see [`FormValidator`]
+    #[prop_or_default]
+    pub validators: Vec<FormValidator>,
+
+    /// Reports if any validation is still running, see [`AsyncValidator`]
+    #[prop_or_default]
+    pub onpending: Callback<bool>,
+
+    /// Called when the form gets submitted.
+    ///
+    /// Submitting is blocked while a validation is still running.
     #[prop_or_default]
     pub onsubmit: Callback<SubmitEvent>,
 }
@@ -80,12 +97,13 @@ pub struct FormProperties {
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct ValidationState {
     results: BTreeMap<String, ValidationResult>,
+    form: FormValidationResults,
     state: InputState,
 }
 
 impl ValidationState {
     fn to_state(&self) -> InputState {
-        let mut current = InputState::Default;
+        let mut current = self.form.state();
         for r in self.results.values() {
             if r.state > current {
                 current = r.state;
@@ -107,6 +125,15 @@ impl ValidationState {
             }
         }
 
+        self.update_state()
+    }
+
+    fn push_form_results(&mut self, results: FormValidationResults) -> bool {
+        self.form = results;
+        self.update_state()
+    }
+
+    fn update_state(&mut self) -> bool {
         // update with diff
 
         let state = self.to_state();
@@ -123,11 +150,62 @@ impl ValidationState {
 pub struct ValidationFormContext {
     callback: Callback<GroupValidationResult>,
     state: InputState,
+    onpending: Callback<(String, bool)>,
+    pending: bool,
+    onvalue: Callback<GroupValue>,
+    results: FormValidationResults,
 }
 
 impl ValidationFormContext {
     pub fn new(callback: Callback<GroupValidationResult>, state: InputState) -> Self {
-        Self { callback, state }
+        Self {
+            callback,
+            state,
+            onpending: Default::default(),
+            pending: false,
+            onvalue: Default::default(),
+            results: Default::default(),
+        }
+    }
+
+    /// Track the values of named groups, for form-level validation.
+    pub fn with_values(
+        mut self,
+        onvalue: Callback<GroupValue>,
+        results: FormValidationResults,
+    ) -> Self {
+        self.onvalue = onvalue;
+        self.results = results;
+        self
+    }
+
+    pub fn push_value(&self, name: String, value: ValidationContext<Rc<dyn Any>>) {
+        self.onvalue.emit(GroupValue(name, Some(value)));
+    }
+
+    pub fn clear_value(&self, name: String) {
+        self.onvalue.emit(GroupValue(name, None));
+    }
+
+    /// The result of the form-level validators for a named group.
+    pub fn form_result(&self, name: &str) -> Option<&ValidationResult> {
+        self.results.get(name)
+    }
+
+    /// Track groups running a validation.
+    pub fn with_pending(mut self, onpending: Callback<(String, bool)>, pending: bool) -> Self {
+        self.onpending = onpending;
+        self.pending = pending;
+        self
+    }
+
+    /// Check if any group is still running a validation.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    pub fn push_pending(&self, id: String, pending: bool) {
+        self.onpending.emit((id, pending));
     }
 
     pub fn is_error(&self) -> bool {
@@ -145,6 +223,9 @@ impl ValidationFormContext {
 
 pub struct GroupValidationResult(pub String, pub Option<ValidationResult>);
 
+/// The value of a named group, see [`FormValidator`].
+pub struct GroupValue(pub String, pub Option<ValidationContext<Rc<dyn Any>>>);
+
 /// The Form component.
 ///
 /// > A **form** is a group of elements used to collect information from a user in a variety of contexts including in a modal, in a wizard, or on a page. Use cases for forms include tasks reliant on user-inputted information for completion like logging in, registering, configuring settings, or completing surveys.
@@ -156,11 +237,17 @@ pub struct GroupValidationResult(pub String, pub Option<ValidationResult>);
 /// Defined by [`FormProperties`].
 pub struct Form {
     validation: ValidationState,
+    pending: BTreeSet<String>,
+    values: FormValues,
+    /// If no value was changed by the user yet
+    initial: bool,
 }
 
 #[doc(hidden)]
 pub enum FormMsg {
     GroupValidationChanged(GroupValidationResult),
+    GroupPendingChanged(String, bool),
+    GroupValueChanged(GroupValue),
 }
 
 impl Component for Form {
@@ -170,6 +257,9 @@ impl Component for Form {
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
             validation: Default::default(),
+            pending: Default::default(),
+            values: Default::default(),
+            initial: true,
         }
     }
 
@@ -182,9 +272,36 @@ impl Component for Form {
                 }
                 changed
             }
+            FormMsg::GroupPendingChanged(id, pending) => {
+                let before = !self.pending.is_empty();
+                match pending {
+                    true => self.pending.insert(id),
+                    false => self.pending.remove(&id),
+                };
+                let after = !self.pending.is_empty();
+                if before != after {
+                    ctx.props().onpending.emit(after);
+                }
+                before != after
+            }
+            FormMsg::GroupValueChanged(GroupValue(name, value)) => {
+                match value {
+                    Some(value) => {
+                        self.initial &= value.initial;
+                        self.values.insert_any(name, value.value);
+                    }
+                    None => self.values.remove(&name),
+                }
+                self.validate(ctx)
+            }
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.validate(ctx);
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let mut classes = Classes::from("pf-v5-c-form");
 
@@ -225,8 +342,26 @@ impl Component for Form {
         let validation_context = ValidationFormContext::new(
             ctx.link().callback(FormMsg::GroupValidationChanged),
             self.validation.state,
+        )
+        .with_pending(
+            ctx.link()
+                .callback(|(id, pending)| FormMsg::GroupPendingChanged(id, pending)),
+            !self.pending.is_empty(),
+        )
+        .with_values(
+            ctx.link().callback(FormMsg::GroupValueChanged),
+            self.validation.form.clone(),
         );
 
+        // don't submit while validating
+        let onsubmit = {
+            let (onsubmit, pending) = (ctx.props().onsubmit.clone(), !self.pending.is_empty());
+            Callback::from(move |evt: SubmitEvent| match pending {
+                true => evt.prevent_default(),
+                false => onsubmit.emit(evt),
+            })
+        };
+
         html! (
             <ContextProvider<ValidationFormContext> context={validation_context} >
                 <form
@@ -235,7 +370,7 @@ impl Component for Form {
                     id={ctx.props().id.clone()}
                     action={ctx.props().action.clone()}
                     method={ctx.props().method.clone()}
-                    onsubmit={ctx.props().onsubmit.clone()}
+                    {onsubmit}
                 >
 
                     if let Some(alert) = alert {
@@ -259,6 +394,27 @@ impl Component for Form {
 }
 
 impl Form {
+    /// Run the form-level validators, returns if the results changed.
+    fn validate(&mut self, ctx: &Context<Self>) -> bool {
+        let mut results = FormValidationResults::new();
+        for validator in &ctx.props().validators {
+            if let Some(result) = validator.run(ValidationContext {
+                value: self.values.clone(),
+                initial: self.initial,
+            }) {
+                results.extend(result);
+            }
+        }
+
+        if self.validation.form == results {
+            return false;
+        }
+        if self.validation.push_form_results(results) {
+            ctx.props().onvalidated.emit(self.validation.state);
+        }
+        true
+    }
+
     fn make_alert(
         state: InputState,
         warning: (&str, &Html),
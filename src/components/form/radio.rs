@@ -1,5 +1,8 @@
 use crate::ouia;
-use crate::prelude::OuiaComponentType;
+use crate::prelude::{
+    use_validation, InputState, OuiaComponentType, ValidatingComponent,
+    ValidatingComponentProperties, ValidationContext,
+};
 use crate::utils::OuiaSafe;
 use crate::{prelude::use_prop_id, utils::Ouia};
 use std::mem::swap;
@@ -8,7 +11,7 @@ use yew::prelude::*;
 const OUIA: Ouia = ouia!("Radio");
 
 /// Properties for [`Radio`].
-#[derive(PartialEq, Properties)]
+#[derive(Clone, PartialEq, Properties)]
 pub struct RadioProperties {
     /// Additional classes added to the radio button.
     #[prop_or_default]
@@ -58,6 +61,14 @@ pub struct RadioProperties {
     #[prop_or_default]
     pub input_onclick: Option<Callback<MouseEvent>>,
 
+    /// The validation state of the radio button.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called with the value, when validation of a checked radio button should occur.
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<Option<AttrValue>>>,
+
     /// Creates a non-standalone input with a label, even if there are no children to this radio.
     #[prop_or_default]
     pub force_label: bool,
@@ -73,6 +84,20 @@ pub struct RadioProperties {
     pub ouia_safe: OuiaSafe,
 }
 
+impl ValidatingComponent for Radio {
+    type Value = Option<AttrValue>;
+}
+
+impl ValidatingComponentProperties<Option<AttrValue>> for RadioProperties {
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<Option<AttrValue>>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
+}
+
 /// Radio button component
 ///
 /// > A **radio** button is used to present the user with mutually exclusive choices. Always present radio buttons in groups of 2 or more.
@@ -82,12 +107,19 @@ pub struct RadioProperties {
 /// ## Properties
 ///
 /// Defined by [`RadioProperties`].
+///
+/// ## Validation
+///
+/// When wrapping the radio buttons of a group with a
+/// [`FormGroupValidated`](crate::prelude::FormGroupValidated), only the checked radio button
+/// reports its value. So a group should have an initial selection.
 #[function_component(Radio)]
 pub fn radio(props: &RadioProperties) -> Html {
     let ouia_id = use_memo(props.ouia_id.clone(), |id| {
         id.clone().unwrap_or(OUIA.generated_id())
     });
     let class = classes!("pf-v5-c-radio", props.class.clone());
+    let (class, aria_invalid) = props.state.convert(class);
 
     let id = use_prop_id(props.id.clone());
 
@@ -97,9 +129,33 @@ pub fn radio(props: &RadioProperties) -> Html {
         input_class.extend(classes!("pf-m-standalone"));
     }
 
-    let onchange = use_callback(props.onchange.clone(), |_, onchange| {
-        onchange.emit(());
-    });
+    // validation, also when the parent changes the state, but only reported by the checked one
+    {
+        let onvalidate = props.onvalidate.clone();
+        use_validation(
+            props.checked.then(|| props.value.clone()),
+            Callback::from(move |ctx: ValidationContext<Option<Option<AttrValue>>>| {
+                if let Some(value) = ctx.value {
+                    onvalidate.emit(ValidationContext {
+                        value,
+                        initial: ctx.initial,
+                    });
+                }
+            }),
+        );
+    }
+
+    let onchange = use_callback(
+        (
+            props.onchange.clone(),
+            props.onvalidate.clone(),
+            props.value.clone(),
+        ),
+        |_, (onchange, onvalidate, value)| {
+            onchange.emit(());
+            onvalidate.emit(value.clone().into());
+        },
+    );
 
     let mut first = html!(
         <input
@@ -110,6 +166,7 @@ pub fn radio(props: &RadioProperties) -> Html {
             checked={props.checked}
             disabled={props.disabled}
             value={&props.value}
+            aria-invalid={aria_invalid.to_string()}
             {onchange}
             onclick={props.input_onclick.clone()}
             data-ouia-component-id={(*ouia_id).clone()}
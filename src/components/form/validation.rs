@@ -1,5 +1,6 @@
-use crate::prelude::{AsClasses, FormHelperText, Icon, ValidationContext};
-use yew::{Callback, Classes};
+use crate::prelude::{AsClasses, FormHelperText, Icon, ValidationContext, Validator};
+use std::{any::Any, collections::BTreeMap, fmt, rc::Rc};
+use yew::{hook, use_effect_with, use_mut_ref, Callback, Classes};
 
 /// State of an input from validation
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -109,8 +110,151 @@ impl ValidationResult {
     pub fn warning<S: Into<String>>(message: S) -> Self {
         Self::new(InputState::Warning, message)
     }
+
+    /// Pick the more severe result, preferring this one.
+    pub(crate) fn most_severe(self, other: Self) -> Self {
+        match other.state > self.state {
+            true => other,
+            false => self,
+        }
+    }
+}
+
+/// The values of the named fields of a [`Form`](crate::prelude::Form).
+///
+/// Fields are named using the `name` property of
+/// [`FormGroupValidated`](crate::prelude::FormGroupValidated), and are available after their
+/// first validation.
+#[derive(Clone, Default)]
+pub struct FormValues(BTreeMap<String, Rc<dyn Any>>);
+
+impl FormValues {
+    /// Get the value of a field, if the field exists and has the requested type.
+    pub fn get<T: 'static>(&self, name: &str) -> Option<&T> {
+        self.0.get(name).and_then(|value| value.downcast_ref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn insert<T: 'static>(&mut self, name: impl Into<String>, value: T) {
+        self.0.insert(name.into(), Rc::new(value));
+    }
+
+    pub(crate) fn insert_any(&mut self, name: String, value: Rc<dyn Any>) {
+        self.0.insert(name, value);
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+}
+
+impl fmt::Debug for FormValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+/// Results of a form-level validator, attached to named fields.
+///
+/// Results of fields which don't exist still contribute to the overall state of the form.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormValidationResults(BTreeMap<String, ValidationResult>);
+
+impl FormValidationResults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a result to a field, keeping the more severe one if the field already has one.
+    pub fn add(&mut self, name: impl Into<String>, result: ValidationResult) {
+        let name = name.into();
+        let result = match self.0.remove(&name) {
+            Some(current) => current.most_severe(result),
+            None => result,
+        };
+        self.0.insert(name, result);
+    }
+
+    /// Attach a result to a field, see [`Self::add`].
+    pub fn with(mut self, name: impl Into<String>, result: ValidationResult) -> Self {
+        self.add(name, result);
+        self
+    }
+
+    /// Add all results of the other results.
+    pub fn extend(&mut self, other: Self) {
+        for (name, result) in other.0 {
+            self.add(name, result);
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ValidationResult> {
+        self.0.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The most severe state of all results.
+    pub fn state(&self) -> InputState {
+        self.0
+            .values()
+            .map(|result| result.state)
+            .max()
+            .unwrap_or_default()
+    }
 }
 
+/// A form-level validator, seeing the values of all named fields.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let rules: Vec<FormValidator> = vec![Validator::from(
+///     |ctx: ValidationContext<FormValues>| {
+///       let mut results = FormValidationResults::new();
+///       if ctx.value.get::<String>("password") != ctx.value.get::<String>("confirm") {
+///         results.add("confirm", ValidationResult::error("The passwords don't match"));
+///       }
+///       results
+///     },
+///   )];
+///
+///   html!(
+///     <Form validators={rules}>
+///       <FormGroupValidated<TextInput>
+///         label="Password"
+///         name="password"
+///         validator={validators::required()}
+///       >
+///         <TextInput r#type={TextInputType::Password} />
+///       </FormGroupValidated<TextInput>>
+///       <FormGroupValidated<TextInput>
+///         label="Confirm password"
+///         name="confirm"
+///         validator={Validator::None}
+///       >
+///         <TextInput r#type={TextInputType::Password} />
+///       </FormGroupValidated<TextInput>>
+///     </Form>
+///   )
+/// }
+/// ```
+pub type FormValidator = Validator<FormValues, FormValidationResults>;
+
 /// A component supporting validation.
 pub trait ValidatingComponent {
     type Value;
@@ -121,3 +265,62 @@ pub trait ValidatingComponentProperties<T> {
     fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<T>>);
     fn set_input_state(&mut self, state: InputState);
 }
+
+/// Request a validation of the initial value, and whenever the value changes.
+///
+/// For components which don't have a single change event, e.g. because their value is only
+/// changed through their properties.
+#[hook]
+pub(crate) fn use_validation<T>(value: T, onvalidate: Callback<ValidationContext<T>>)
+where
+    T: Clone + PartialEq + 'static,
+{
+    let initial = use_mut_ref(|| true);
+    use_effect_with(value, move |value| {
+        let initial = initial.replace(false);
+        onvalidate.emit(ValidationContext {
+            value: value.clone(),
+            initial,
+        });
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_values() {
+        let mut values = FormValues::default();
+        values.insert("name", "foo".to_string());
+        values.insert("count", 1u32);
+
+        assert_eq!(
+            values.get::<String>("name").map(String::as_str),
+            Some("foo")
+        );
+        assert_eq!(values.get::<u32>("count"), Some(&1));
+        // wrong type
+        assert_eq!(values.get::<i32>("count"), None);
+        assert!(!values.contains("other"));
+
+        values.remove("name");
+        assert_eq!(values.names().collect::<Vec<_>>(), vec!["count"]);
+    }
+
+    #[test]
+    fn test_results() {
+        let mut results = FormValidationResults::new()
+            .with("a", ValidationResult::warning("warning"))
+            .with("a", ValidationResult::ok());
+        assert_eq!(
+            results.get("a"),
+            Some(&ValidationResult::warning("warning"))
+        );
+        assert_eq!(results.state(), InputState::Warning);
+
+        results.extend(FormValidationResults::new().with("b", ValidationResult::error("error")));
+        assert_eq!(results.state(), InputState::Error);
+        assert_eq!(FormValidationResults::new().state(), InputState::Default);
+    }
+}
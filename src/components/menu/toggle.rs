@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -30,6 +31,16 @@ pub struct MenuToggleProperties {
     #[prop_or_default]
     pub variant: MenuToggleVariant,
 
+    /// Render a split button, having a checkbox before the toggle.
+    ///
+    /// The text will be used as the label of the checkbox.
+    #[prop_or_default]
+    pub checkbox: Option<MenuToggleCheckbox>,
+
+    /// The validation state, shown as status
+    #[prop_or_default]
+    pub state: InputState,
+
     #[prop_or_default]
     pub ontoggle: Callback<()>,
 
@@ -57,6 +68,15 @@ impl AsClasses for MenuToggleVariant {
     }
 }
 
+/// The checkbox of a split button [`MenuToggle`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuToggleCheckbox {
+    pub state: CheckboxState,
+    pub aria_label: AttrValue,
+    /// Called with the new checked state
+    pub onchange: Callback<bool>,
+}
+
 /// Menu toggle component
 ///
 /// See: <https://www.patternfly.org/components/menus/menu-toggle>
@@ -66,6 +86,18 @@ impl AsClasses for MenuToggleVariant {
 /// Defined by [`MenuToggleProperties`].
 #[function_component(MenuToggle)]
 pub fn menu_toggle(props: &MenuToggleProperties) -> Html {
+    let check_ref = use_node_ref();
+
+    {
+        let check_ref = check_ref.clone();
+        let state = props.checkbox.as_ref().map(|checkbox| checkbox.state);
+        use_effect(move || {
+            if let Some(elem) = check_ref.cast::<HtmlInputElement>() {
+                elem.set_indeterminate(state == Some(CheckboxState::Indeterminate))
+            }
+        });
+    }
+
     let mut class = classes!("pf-v5-c-menu-toggle");
 
     if props.expanded {
@@ -86,6 +118,68 @@ pub fn menu_toggle(props: &MenuToggleProperties) -> Html {
 
     class.extend_from(&props.variant);
 
+    match props.state {
+        InputState::Default => {}
+        InputState::Success => class.push(classes!("pf-m-success")),
+        InputState::Warning => class.push(classes!("pf-m-warning")),
+        InputState::Error => class.push(classes!("pf-m-danger")),
+    }
+    let status = (props.state != InputState::Default).then(
+        || html!(<span class="pf-v5-c-menu-toggle__status-icon">{ props.state.icon() }</span>),
+    );
+
+    if let Some(checkbox) = &props.checkbox {
+        class.push(classes!("pf-m-split-button"));
+
+        let onchange = {
+            let (onchange, check_ref) = (checkbox.onchange.clone(), check_ref.clone());
+            Callback::from(move |_: Event| {
+                if let Some(input) = check_ref.cast::<HtmlInputElement>() {
+                    onchange.emit(input.checked());
+                }
+            })
+        };
+
+        let mut check_class = classes!("pf-v5-c-check");
+        if props.text.is_none() {
+            check_class.push(classes!("pf-m-standalone"));
+        }
+
+        return html!(
+            <div ref={props.r#ref.clone()} {class}>
+                <label class={check_class}>
+                    <input
+                        ref={check_ref}
+                        class="pf-v5-c-check__input"
+                        type="checkbox"
+                        aria-label={&checkbox.aria_label}
+                        disabled={props.disabled}
+                        checked={checkbox.state == CheckboxState::Checked}
+                        {onchange}
+                    />
+                    if let Some(text) = &props.text {
+                        <span class="pf-v5-c-check__label">{ text }</span>
+                    }
+                </label>
+                <button
+                    class="pf-v5-c-menu-toggle__button"
+                    type="button"
+                    disabled={props.disabled}
+                    aria-expanded={props.expanded.to_string()}
+                    aria-label={&props.aria_label}
+                    onclick={props.ontoggle.reform(|_| ())}
+                >
+                    <span class="pf-v5-c-menu-toggle__controls">
+                        { status }
+                        <span class="pf-v5-c-menu-toggle__toggle-icon">
+                            <i class="fas fa-caret-down" aria-hidden="true"></i>
+                        </span>
+                    </span>
+                </button>
+            </div>
+        );
+    }
+
     let plain = matches!(props.variant, MenuToggleVariant::Plain);
     let text = props.text.is_some();
 
@@ -114,6 +208,7 @@ pub fn menu_toggle(props: &MenuToggleProperties) -> Html {
             if !plain || text {
                 // if we have more than just a plain icon, add the toggle control
                 <span class="pf-v5-c-menu-toggle__controls">
+                    { status }
                     <span class="pf-v5-c-menu-toggle__toggle-icon">
                         <i class="fas fa-caret-down" aria-hidden="true"></i>
                     </span>
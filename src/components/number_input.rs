@@ -46,6 +46,9 @@ pub struct NumberInputProperties<T: PrimInt + Display + FromStr + 'static> {
     /// Value to indicate if the input is modified to show the validiation state.
     #[prop_or_default]
     pub state: InputState,
+    /// Called when validation should occur, initially and whenever the value changes.
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<T>>,
     /// Name of the input.
     #[prop_or_default]
     pub input_name: Option<String>,
@@ -57,6 +60,22 @@ pub struct NumberInputProperties<T: PrimInt + Display + FromStr + 'static> {
     pub plus_button_aria_label: AttrValue,
 }
 
+impl<T: PrimInt + Display + FromStr + 'static> ValidatingComponent for NumberInput<T> {
+    type Value = T;
+}
+
+impl<T: PrimInt + Display + FromStr + 'static> ValidatingComponentProperties<T>
+    for NumberInputProperties<T>
+{
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<T>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
+}
+
 #[function_component(NumberInput)]
 pub fn number_input<T: PrimInt + Display + FromStr + 'static>(
     props: &NumberInputProperties<T>,
@@ -66,6 +85,9 @@ pub fn number_input<T: PrimInt + Display + FromStr + 'static>(
     if props.state != InputState::Default {
         class.push("pf-m-status");
     }
+    // the value is controlled by the properties, so validate it whenever it changes
+    use_validation(props.value, props.onvalidate.clone());
+
     let width_style_name = "--pf-v5-c-number-input--c-form-control--width-chars";
     let style = props
         .width_chars
@@ -4,7 +4,7 @@ use crate::prelude::*;
 use yew::prelude::*;
 
 /// Properties for [`SimpleSelect`].
-#[derive(PartialEq, Properties)]
+#[derive(Clone, PartialEq, Properties)]
 pub struct SimpleSelectProperties<T>
 where
     T: Clone + Eq + SelectItemRenderer,
@@ -20,6 +20,34 @@ where
 
     #[prop_or_default]
     pub onselect: Callback<T>,
+
+    /// The validation state of the select.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called when validation should occur
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<Option<T>>>,
+}
+
+impl<T> ValidatingComponent for SimpleSelect<T>
+where
+    T: Clone + Eq + SelectItemRenderer + 'static,
+{
+    type Value = Option<T>;
+}
+
+impl<T> ValidatingComponentProperties<Option<T>> for SimpleSelectProperties<T>
+where
+    T: Clone + Eq + SelectItemRenderer + 'static,
+{
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<Option<T>>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
 }
 
 /// Render an item for the [`SimpleSelect`] component.
@@ -57,9 +85,21 @@ where
         .map(|s| s.label())
         .or_else(|| props.placeholder.clone());
 
+    // validation, also when the parent changes the selection
+    use_validation(props.selected.clone(), props.onvalidate.clone());
+
+    let onselect = use_callback(
+        (props.onselect.clone(), props.onvalidate.clone()),
+        |entry: T, (onselect, onvalidate)| {
+            onselect.emit(entry.clone());
+            onvalidate.emit(Some(entry).into());
+        },
+    );
+
     html!(
         <Dropdown
             text={text.clone()}
+            state={props.state}
         >
             { for props.entries.iter().map(|entry| {
                 html_nested!(
@@ -67,7 +107,7 @@ where
                         <SimpleSelectItem<T>
                             entry={entry.clone()}
                             selected={props.selected.as_ref() == Some(entry)}
-                            onselect={props.onselect.clone()}
+                            onselect={onselect.clone()}
                         />
                     </Raw>
                 )
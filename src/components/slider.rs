@@ -1,4 +1,7 @@
 //! Slider control
+use crate::prelude::{
+    InputState, ValidatingComponent, ValidatingComponentProperties, ValidationContext,
+};
 use gloo_events::{EventListener, EventListenerOptions};
 use gloo_utils::document;
 use std::fmt::{Display, Formatter};
@@ -91,6 +94,28 @@ pub struct SliderProperties {
 
     #[prop_or_default]
     pub snap_mode: SnapMode,
+
+    /// The validation state of the slider.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called when validation should occur
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<f64>>,
+}
+
+impl ValidatingComponent for Slider {
+    type Value = f64;
+}
+
+impl ValidatingComponentProperties<f64> for SliderProperties {
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<f64>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -160,6 +185,10 @@ impl Component for Slider {
             // initial send a change event
             ctx.props().onchange.emit(value);
         }
+        ctx.props().onvalidate.emit(ValidationContext {
+            value,
+            initial: true,
+        });
 
         let snap_mode = ctx.props().snap_mode;
 
@@ -184,6 +213,7 @@ impl Component for Slider {
                 if self.value != value {
                     self.value = value;
                     ctx.props().onchange.emit(self.value);
+                    ctx.props().onvalidate.emit(self.value.into());
                 } else {
                     return false;
                 }
@@ -226,7 +256,7 @@ impl Component for Slider {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let classes = Classes::from("pf-v5-c-slider");
+        let (classes, aria_invalid) = ctx.props().state.convert(Classes::from("pf-v5-c-slider"));
         let valuestr = format!("{0:.1$}", self.value, ctx.props().label_precision);
         let valuestr = valuestr.trim_end_matches('0').to_string();
 
@@ -271,6 +301,7 @@ impl Component for Slider {
                         aria-valuemax={ctx.props().max.value.to_string()}
                         aria-valuenow={valuestr}
                         aria-label="Value"
+                        aria-invalid={aria_invalid.to_string()}
                         tabindex="0"
                         >
                     </div>
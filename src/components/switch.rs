@@ -1,6 +1,9 @@
 //! Switch control
 use crate::ouia;
-use crate::prelude::{Icon, OuiaComponentType};
+use crate::prelude::{
+    use_validation, Icon, InputState, OuiaComponentType, ValidatingComponent,
+    ValidatingComponentProperties, ValidationContext,
+};
 use crate::utils::{Ouia, OuiaSafe};
 use web_tools::prelude::*;
 use yew::prelude::*;
@@ -26,6 +29,14 @@ pub struct SwitchProperties {
     #[prop_or_default]
     pub onchange: Callback<bool>,
 
+    /// The validation state of the switch.
+    #[prop_or_default]
+    pub state: InputState,
+
+    /// Called when validation should occur
+    #[prop_or_default]
+    pub onvalidate: Callback<ValidationContext<bool>>,
+
     #[prop_or_default]
     pub aria_label: String,
 
@@ -40,6 +51,20 @@ pub struct SwitchProperties {
     pub ouia_safe: OuiaSafe,
 }
 
+impl ValidatingComponent for Switch {
+    type Value = bool;
+}
+
+impl ValidatingComponentProperties<bool> for SwitchProperties {
+    fn set_onvalidate(&mut self, onvalidate: Callback<ValidationContext<bool>>) {
+        self.onvalidate = onvalidate;
+    }
+
+    fn set_input_state(&mut self, state: InputState) {
+        self.state = state;
+    }
+}
+
 /// Switch component
 ///
 /// > A **switch** toggles the state of a setting (between on and off). Switches and checkboxes can often be used interchangeably, but the switch provides a more explicit, visible representation on a setting.
@@ -56,14 +81,27 @@ pub fn switch(props: &SwitchProperties) -> Html {
     });
     let input_ref = use_node_ref();
 
+    // validation, also when the parent changes the state
+    use_validation(props.checked, props.onvalidate.clone());
+
     let onchange = use_callback(
-        (input_ref.clone(), props.onchange.clone()),
-        |_evt, (input_ref, onchange)| onchange.emit(input_ref.checked()),
+        (
+            input_ref.clone(),
+            props.onchange.clone(),
+            props.onvalidate.clone(),
+        ),
+        |_evt, (input_ref, onchange, onvalidate)| {
+            let checked = input_ref.checked();
+            onchange.emit(checked);
+            onvalidate.emit(checked.into());
+        },
     );
 
+    let (class, aria_invalid) = props.state.convert(classes!("pf-v5-c-switch"));
+
     html! (
         <label
-            class="pf-v5-c-switch"
+            {class}
             for={props.id.clone()}
             data-ouia-component-id={(*ouia_id).clone()}
             data-ouia-component-type={props.ouia_type}
@@ -77,6 +115,7 @@ pub fn switch(props: &SwitchProperties) -> Html {
                 aria-label={props.aria_label.clone()}
                 checked={props.checked}
                 disabled={props.disabled}
+                aria-invalid={aria_invalid.to_string()}
                 {onchange}
             />
             <span class="pf-v5-c-switch__toggle">
@@ -0,0 +1,309 @@
+use super::TableData;
+use crate::prelude::{
+    use_backdrop, Backdropper, Bullseye, Button, ButtonVariant, Dropdown, Icon, MenuAction,
+    MenuChildVariant, MenuToggleVariant, Modal, ModalVariant, Tooltip,
+};
+use yew::prelude::*;
+
+/// A confirmation, required before running a [`RowAction`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowActionConfirm {
+    pub title: String,
+    pub message: String,
+}
+
+/// An action on a single row of a table.
+///
+/// Actions are either rendered inline, as buttons, or as items of the "kebab" menu of the row.
+/// When triggered, the action is called with the key of the row.
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::prelude::*;
+///
+/// let ondelete = yew::Callback::from(|key: usize| log::info!("Delete: {key}"));
+/// let locked = true;
+///
+/// let action = RowAction::new("Delete", ondelete)
+///     .icon(Icon::Trash)
+///     .danger()
+///     .disabled(locked, "The entry is locked");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowAction<K> {
+    pub label: String,
+    pub icon: Option<Icon>,
+    /// Render as a button, rather than a menu item
+    pub inline: bool,
+    pub danger: bool,
+    /// The reason why the action is disabled, shown as its tooltip
+    pub disabled: Option<String>,
+    pub confirm: Option<RowActionConfirm>,
+    pub onclick: Callback<K>,
+}
+
+impl<K> RowAction<K> {
+    pub fn new(label: impl Into<String>, onclick: Callback<K>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            inline: false,
+            danger: false,
+            disabled: None,
+            confirm: None,
+            onclick,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Option<Icon>>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Render the action as a button, rather than a menu item.
+    pub fn inline(mut self) -> Self {
+        self.inline = true;
+        self
+    }
+
+    /// Mark the action as dangerous.
+    ///
+    /// Unless a different confirmation is set, this requires a generic confirmation before
+    /// running the action.
+    pub fn danger(mut self) -> Self {
+        self.danger = true;
+        if self.confirm.is_none() {
+            self.confirm = Some(RowActionConfirm {
+                title: format!("{}?", self.label),
+                message: "This action cannot be undone.".into(),
+            });
+        }
+        self
+    }
+
+    /// Require a confirmation before running the action.
+    pub fn confirm(mut self, title: impl Into<String>, message: impl Into<String>) -> Self {
+        self.confirm = Some(RowActionConfirm {
+            title: title.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Disable the action, if the condition is met, explaining why.
+    pub fn disabled(mut self, disabled: bool, reason: impl Into<String>) -> Self {
+        self.disabled = disabled.then(|| reason.into());
+        self
+    }
+}
+
+impl<K> RowAction<K>
+where
+    K: Clone + 'static,
+{
+    /// Create the callback triggering the action on a row, asking for confirmation if required.
+    fn trigger(&self, key: K, backdrop: Option<Backdropper>) -> Callback<()> {
+        let (onclick, danger) = (self.onclick.clone(), self.danger);
+        let (label, confirm) = (self.label.clone(), self.confirm.clone());
+        Callback::from(move |()| {
+            let Some(confirm) = &confirm else {
+                onclick.emit(key.clone());
+                return;
+            };
+
+            let Some(backdrop) = &backdrop else {
+                // no backdrop to show a modal in, fall back to the browser
+                let message = format!("{}\n\n{}", confirm.title, confirm.message);
+                if gloo_utils::window()
+                    .confirm_with_message(&message)
+                    .unwrap_or_default()
+                {
+                    onclick.emit(key.clone());
+                }
+                return;
+            };
+
+            let onconfirm = {
+                let (backdrop, onclick, key) = (backdrop.clone(), onclick.clone(), key.clone());
+                Callback::from(move |_| {
+                    backdrop.close();
+                    onclick.emit(key.clone());
+                })
+            };
+            let oncancel = {
+                let backdrop = backdrop.clone();
+                Callback::from(move |_| backdrop.close())
+            };
+            let variant = match danger {
+                true => ButtonVariant::Danger,
+                false => ButtonVariant::Primary,
+            };
+            let footer = html!(<>
+                <Button {variant} label={label.clone()} onclick={onconfirm} />
+                <Button variant={ButtonVariant::Link} label="Cancel" onclick={oncancel} />
+            </>);
+
+            backdrop.open(html!(
+                <Bullseye>
+                    <Modal title={confirm.title.clone()} variant={ModalVariant::Small} {footer}>
+                        <p>{ &confirm.message }</p>
+                    </Modal>
+                </Bullseye>
+            ));
+        })
+    }
+}
+
+/// Provides the actions of a row, based on its item.
+pub type RowActionsCallback<T, K> = Callback<T, Vec<RowAction<K>>>;
+
+/// Properties for [`TableRowActions`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct TableRowActionsProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// The key of the row
+    pub entry_key: K,
+
+    #[prop_or_default]
+    pub actions: Vec<RowAction<K>>,
+
+    /// Additional items of the "kebab" menu
+    #[prop_or_default]
+    pub menu: Vec<MenuChildVariant>,
+}
+
+/// The action cell of a table row.
+///
+/// Renders the inline actions as buttons, followed by a "kebab" menu containing all other
+/// actions. Disabled actions show the reason as a tooltip, or as the description of their menu
+/// item.
+///
+/// Confirmations are shown as a modal dialog, when wrapped by a
+/// [`crate::prelude::BackdropViewer`]. Otherwise, the browser's confirmation dialog is used.
+///
+/// This is used by the [`super::Table`], based on its `row_actions` property. Using it directly
+/// allows the same actions for the rows of a [`super::ComposableTable`].
+#[function_component(TableRowActions)]
+pub fn table_row_actions<K>(props: &TableRowActionsProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let backdrop = use_backdrop();
+
+    let (inline, menu): (Vec<_>, Vec<_>) = props.actions.iter().partition(|action| action.inline);
+
+    let buttons = inline
+        .into_iter()
+        .map(|action| {
+            let trigger = action.trigger(props.entry_key.clone(), backdrop.clone());
+            // don't click the row
+            let onclick = Callback::from(move |evt: MouseEvent| {
+                evt.stop_propagation();
+                trigger.emit(());
+            });
+            let variant = match action.danger {
+                true => ButtonVariant::DangerSecondary,
+                false => ButtonVariant::Secondary,
+            };
+            let button = html!(
+                <Button
+                    {variant}
+                    icon={action.icon}
+                    label={action.label.clone()}
+                    aria_disabled={action.disabled.is_some()}
+                    {onclick}
+                />
+            );
+            let content = match &action.disabled {
+                Some(reason) => html!(<Tooltip text={reason.clone()}>{ button }</Tooltip>),
+                None => button,
+            };
+            html!(<div class="pf-v5-c-action-list__item">{ content }</div>)
+        })
+        .collect::<Vec<_>>();
+
+    let items = menu
+        .into_iter()
+        .map(|action| {
+            MenuChildVariant::from(html_nested!(
+                <MenuAction
+                    icon={action.icon.map(|icon| html!(icon))}
+                    danger={action.danger}
+                    disabled={action.disabled.is_some()}
+                    description={action.disabled.clone()}
+                    onclick={action.trigger(props.entry_key.clone(), backdrop.clone())}
+                >
+                    { &action.label }
+                </MenuAction>
+            ))
+        })
+        .chain(props.menu.iter().cloned())
+        .collect::<Vec<_>>();
+
+    if buttons.is_empty() && items.is_empty() {
+        return html!();
+    }
+
+    let kebab = (!items.is_empty()).then(|| {
+        html!(
+            <Dropdown
+                variant={MenuToggleVariant::Plain}
+                icon={Icon::EllipsisV}
+                aria_label="Actions"
+            >
+                { items }
+            </Dropdown>
+        )
+    });
+
+    html!(
+        <TableData action=true>
+            if buttons.is_empty() {
+                { kebab }
+            } else {
+                <div class="pf-v5-c-action-list">
+                    { buttons }
+                    if let Some(kebab) = kebab {
+                        <div class="pf-v5-c-action-list__item">{ kebab }</div>
+                    }
+                </div>
+            }
+        </TableData>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_danger_confirms() {
+        let action = RowAction::<usize>::new("Delete", Callback::noop()).danger();
+        assert_eq!(
+            action.confirm,
+            Some(RowActionConfirm {
+                title: "Delete?".into(),
+                message: "This action cannot be undone.".into(),
+            })
+        );
+
+        let action = RowAction::<usize>::new("Delete", Callback::noop())
+            .confirm("Delete the entry?", "It will be gone.")
+            .danger();
+        assert_eq!(
+            action.confirm.map(|confirm| confirm.title),
+            Some("Delete the entry?".into())
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        let action = RowAction::<usize>::new("Edit", Callback::noop()).disabled(false, "Locked");
+        assert_eq!(action.disabled, None);
+        let action = action.disabled(true, "Locked");
+        assert_eq!(action.disabled.as_deref(), Some("Locked"));
+    }
+}
@@ -0,0 +1,256 @@
+use super::{TableSelection, UseTableSelection};
+use crate::prelude::{
+    Button, ButtonVariant, CheckboxState, Dropdown, MenuAction, MenuToggleCheckbox,
+};
+use std::hash::Hash;
+use yew::prelude::*;
+
+/// Select exactly the provided keys.
+pub(crate) fn select_only<'k, K>(keys: impl IntoIterator<Item = &'k K>) -> TableSelection<K>
+where
+    K: Clone + Hash + Eq + 'k,
+{
+    let mut selection = TableSelection::default();
+    selection.set_all(keys, true);
+    selection
+}
+
+/// The label of a bulk select menu item.
+fn items(count: usize) -> String {
+    match count {
+        1 => "1 item".to_string(),
+        n => format!("{n} items"),
+    }
+}
+
+/// Properties for [`TableBulkSelect`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TableBulkSelectProperties<K>
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    /// The selection of the table, created using [`super::use_table_selection`].
+    ///
+    /// For a paged table, use [`super::use_table_selection_with`], so that "select all" selects
+    /// the rows of all pages.
+    pub selection: UseTableSelection<K>,
+
+    /// The keys of the rows of the current page.
+    ///
+    /// Enables the "select page" item, as well as selecting the page using the checkbox. Defaults
+    /// to the current rows of the selection, if it is paged.
+    #[prop_or_default]
+    pub page: Option<Vec<K>>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Bulk select of table rows.
+///
+/// A split button, having a checkbox reflecting the state of the selection, and a menu to select
+/// none, the current page, or all rows. Checking the checkbox selects the current page, if
+/// provided, or all rows otherwise.
+///
+/// This should be placed in a [`crate::prelude::ToolbarItem`] of type
+/// [`crate::prelude::ToolbarItemType::BulkSelect`], along with a [`TableBulkAction`] for each
+/// action on the selected rows.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name };
+/// #[derive(Clone)]
+/// struct ExampleEntry { name: String };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let entries = use_memo((), |()| {
+///       vec![
+///           ExampleEntry { name: "foo".into() },
+///           ExampleEntry { name: "bar".into() },
+///       ]
+///   });
+///
+///   let model = MemoizedTableModel::new(entries);
+///   let selection = use_table_selection(model.clone());
+///   let (entries, onexpand) = use_table_data(model);
+///
+///   let ondelete = Callback::from(|keys: Vec<usize>| {
+///     log::info!("Delete: {keys:?}");
+///   });
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <>
+///       <Toolbar>
+///         <ToolbarContent>
+///           <ToolbarItem r#type={ToolbarItemType::BulkSelect}>
+///             <TableBulkSelect<usize> selection={selection.clone()} />
+///           </ToolbarItem>
+///           <ToolbarItem>
+///             <TableBulkAction<usize> selection={selection.clone()} onclick={ondelete}>
+///               { "Delete" }
+///             </TableBulkAction<usize>>
+///           </ToolbarItem>
+///         </ToolbarContent>
+///       </Toolbar>
+///       <Table<Column, UseTableData<Column, MemoizedTableModel<ExampleEntry>>>
+///         {header}
+///         {entries}
+///         {onexpand}
+///         {selection}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[function_component(TableBulkSelect)]
+pub fn table_bulk_select<K>(props: &TableBulkSelectProperties<K>) -> Html
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    let selection = &props.selection;
+    let count = selection.selected_keys().len();
+    let total = selection.total();
+    let page = props
+        .page
+        .clone()
+        .or_else(|| selection.is_paged().then(|| selection.keys().to_vec()));
+
+    let onnone = {
+        let onchange = selection.onchange.clone();
+        Callback::from(move |()| onchange.emit(TableSelection::default()))
+    };
+    let onpage = {
+        let (onchange, page) = (selection.onchange.clone(), page.clone());
+        Callback::from(move |()| {
+            if let Some(page) = &page {
+                onchange.emit(select_only(page));
+            }
+        })
+    };
+    let onall = selection.onselectall.reform(|()| true);
+
+    let checkbox = MenuToggleCheckbox {
+        state: match count {
+            0 => CheckboxState::Unchecked,
+            n if Some(n) == total => CheckboxState::Checked,
+            _ => CheckboxState::Indeterminate,
+        },
+        aria_label: "Select page".into(),
+        onchange: {
+            let (onnone, onpage, onall) = (onnone.clone(), onpage.clone(), onall.clone());
+            let page = page.is_some();
+            Callback::from(move |checked| match (checked, page) {
+                (false, _) => onnone.emit(()),
+                (true, true) => onpage.emit(()),
+                (true, false) => onall.emit(()),
+            })
+        },
+    };
+
+    let text = (count > 0).then(|| format!("{count} selected"));
+
+    html!(
+        <Dropdown
+            {checkbox}
+            {text}
+            aria_label="Bulk select"
+            disabled={props.disabled}
+        >
+            <MenuAction onclick={onnone}>{ "Select none (0 items)" }</MenuAction>
+            { for page.as_ref().map(|page| html_nested!(
+                <MenuAction onclick={onpage.clone()}>
+                    { format!("Select page ({})", items(page.len())) }
+                </MenuAction>
+            )) }
+            <MenuAction onclick={onall}>
+                { match total {
+                    Some(total) => format!("Select all ({})", items(total)),
+                    None => "Select all".to_string(),
+                } }
+            </MenuAction>
+        </Dropdown>
+    )
+}
+
+/// Properties for [`TableBulkAction`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TableBulkActionProperties<K>
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    /// The selection of the table, also see [`TableBulkSelect`].
+    pub selection: UseTableSelection<K>,
+
+    /// Called with the keys of the selected rows, in the order of the rows.
+    pub onclick: Callback<Vec<K>>,
+
+    #[prop_or_default]
+    pub children: Html,
+
+    #[prop_or(ButtonVariant::Secondary)]
+    pub variant: ButtonVariant,
+
+    /// Disable the action, even when rows are selected.
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// An action on the selected rows of a table.
+///
+/// The action is disabled as long as no row is selected. Also see [`TableBulkSelect`].
+#[function_component(TableBulkAction)]
+pub fn table_bulk_action<K>(props: &TableBulkActionProperties<K>) -> Html
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    let selected = props.selection.selected_keys();
+    let disabled = props.disabled || selected.is_empty();
+    let onclick = props.onclick.reform(move |_| selected.clone());
+
+    html!(
+        <Button variant={props.variant} {disabled} {onclick}>
+            { props.children.clone() }
+        </Button>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_select_only() {
+        let selection = select_only(&[1, 3]);
+        assert!(selection.is_selected(&1));
+        assert!(!selection.is_selected(&2));
+        assert!(selection.is_selected(&3));
+        assert_eq!(selection.len(), 2);
+    }
+
+    #[test]
+    fn test_items() {
+        assert_eq!(items(0), "0 items");
+        assert_eq!(items(1), "1 item");
+        assert_eq!(items(10), "10 items");
+    }
+}
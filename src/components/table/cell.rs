@@ -1,4 +1,7 @@
+use super::editing::Update;
 use super::props::TextModifier;
+use crate::prelude::ValidationResult;
+use std::rc::Rc;
 use yew::prelude::*;
 
 /// A rendered cell.
@@ -39,3 +42,29 @@ impl From<Html> for Cell {
 pub struct CellContext<'c, C> {
     pub column: &'c C,
 }
+
+/// The context information for rendering the edit widget of a cell.
+pub struct EditCellContext<'c, C, T> {
+    pub column: &'c C,
+    /// The result of validating the column, using [`super::TableEntryEditor::validator`].
+    pub validation: ValidationResult,
+    pub(crate) onupdate: Callback<Update<T>>,
+}
+
+impl<C, T> EditCellContext<'_, C, T>
+where
+    T: 'static,
+{
+    /// Create a callback, applying a value of an edit widget to the item being edited.
+    pub fn update<V, F>(&self, f: F) -> Callback<V>
+    where
+        V: 'static,
+        F: Fn(&mut T, V) + 'static,
+    {
+        let f = Rc::new(f);
+        self.onupdate.reform(move |value: V| {
+            let f = f.clone();
+            Box::new(move |item: &mut T| f(item, value)) as Update<T>
+        })
+    }
+}
@@ -1,5 +1,7 @@
+use super::resize::{ColumnResizeContext, ResizeHandle, DEFAULT_MIN_COLUMN_WIDTH};
 use crate::prelude::{
-    AsClasses, ExtendClasses, Icon, Order, TableHeaderContext, TableHeaderSortBy, TextModifier,
+    AsClasses, ExtendClasses, Icon, Order, TableHeaderContext, TableHeaderSortBy,
+    TableHeaderSortEvent, TextModifier,
 };
 use std::fmt::Debug;
 use yew::prelude::*;
@@ -18,6 +20,14 @@ where
     pub center: bool,
     #[prop_or_default]
     pub width: ColumnWidth,
+    /// Allow the user to resize the column, dragging its border.
+    ///
+    /// The widths are reported by the `onresize` property of the [`super::TableHeader`].
+    #[prop_or_default]
+    pub resizable: bool,
+    /// The minimum width of a resizable column, in pixels.
+    #[prop_or(DEFAULT_MIN_COLUMN_WIDTH)]
+    pub min_width: u32,
     #[prop_or_default]
     pub text_modifier: Option<TextModifier>,
     #[prop_or_default]
@@ -27,12 +37,197 @@ where
     #[prop_or_default]
     pub(crate) first_tree_column: bool,
 
+    /// Keep the column visible when scrolling the table horizontally.
+    ///
+    /// The cells of the column will be sticky as well, and the table will be wrapped with the
+    /// required scroll containers.
+    #[prop_or_default]
+    pub sticky: Option<StickyColumn>,
+
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub(crate) sticky_border: bool,
+
     // Current sortby status
     #[prop_or_default]
     pub sortby: Option<TableHeaderSortBy<C>>,
 
     #[prop_or_default]
     pub onsort: Option<Callback<TableHeaderSortBy<C>>>,
+
+    /// Sort callback, also reporting if the sort criteria should be extended.
+    ///
+    /// Holding the shift key while clicking the column will request to add the column to the
+    /// current sort criteria, rather than replacing them. Also see [`crate::prelude::use_table_sorting`].
+    #[prop_or_default]
+    pub onsortevent: Option<Callback<TableHeaderSortEvent<C>>>,
+}
+
+impl<C> TableColumnProperties<C>
+where
+    C: Clone + Eq + 'static,
+{
+    /// Check if the column is sortable
+    pub fn is_sortable(&self) -> bool {
+        self.onsort.is_some() || self.onsortevent.is_some()
+    }
+}
+
+/// The side a sticky column sticks to.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum StickyPosition {
+    /// Stick to the start (left) of the table
+    #[default]
+    Start,
+    /// Stick to the end (right) of the table
+    End,
+}
+
+/// Settings of a sticky column.
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct StickyColumn {
+    pub position: StickyPosition,
+    /// The offset from the side it sticks to, as CSS value.
+    ///
+    /// Required when having more than one sticky column on the same side, in which case this
+    /// needs to be the combined width of the sticky columns before it. The width of the leading
+    /// control cells (like the selection checkbox) is added automatically.
+    pub offset: Option<AttrValue>,
+    /// The minimum width of the column, as CSS value.
+    pub min_width: Option<AttrValue>,
+}
+
+impl StickyColumn {
+    pub fn start() -> Self {
+        Self::default()
+    }
+
+    pub fn end() -> Self {
+        Self {
+            position: StickyPosition::End,
+            ..Default::default()
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<AttrValue>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn min_width(mut self, min_width: impl Into<AttrValue>) -> Self {
+        self.min_width = Some(min_width.into());
+        self
+    }
+
+    /// Add the classes of a sticky cell, returning the required style.
+    pub(crate) fn apply(&self, border: bool, class: &mut Classes) -> Option<String> {
+        class.push("pf-v5-c-table__sticky-cell");
+
+        let mut style = String::new();
+        match self.position {
+            StickyPosition::Start => {
+                if border {
+                    class.push("pf-m-border-right");
+                }
+                if let Some(offset) = &self.offset {
+                    style.push_str(&format!("--pf-v5-c-table__sticky-cell--Left: {offset};"));
+                }
+            }
+            StickyPosition::End => {
+                class.push("pf-m-right");
+                if border {
+                    class.push("pf-m-border-left");
+                }
+                if let Some(offset) = &self.offset {
+                    style.push_str(&format!("--pf-v5-c-table__sticky-cell--Right: {offset};"));
+                }
+            }
+        }
+        if let Some(min_width) = &self.min_width {
+            style.push_str(&format!(
+                "--pf-v5-c-table__sticky-cell--MinWidth: {min_width};"
+            ));
+        }
+
+        (!style.is_empty()).then_some(style)
+    }
+}
+
+/// Evaluate which sticky columns need a border, separating them from the scrolling columns.
+///
+/// That is the last column sticking to the start, and the first column sticking to the end.
+pub(crate) fn sticky_borders(positions: &[Option<StickyPosition>]) -> Vec<bool> {
+    let last_start = positions
+        .iter()
+        .rposition(|p| p == &Some(StickyPosition::Start));
+    let first_end = positions
+        .iter()
+        .position(|p| p == &Some(StickyPosition::End));
+
+    (0..positions.len())
+        .map(|n| Some(n) == last_start || Some(n) == first_end)
+        .collect()
+}
+
+/// The width of a sticky control cell (expand toggle, drag handle, or selection checkbox), in rem.
+const CONTROL_CELL_WIDTH: usize = 3;
+
+/// The sticky state of the leading control cell at position `n`.
+///
+/// Control cells are only sticky when at least one column sticks to the start.
+pub(crate) fn sticky_control(
+    n: usize,
+    columns: &[Option<(StickyColumn, bool)>],
+) -> Option<StickyColumn> {
+    columns
+        .iter()
+        .flatten()
+        .any(|(sticky, _)| sticky.position == StickyPosition::Start)
+        .then(|| {
+            StickyColumn::start()
+                .offset(format!("{}rem", n * CONTROL_CELL_WIDTH))
+                .min_width(format!("{CONTROL_CELL_WIDTH}rem"))
+        })
+}
+
+/// Apply [`sticky_borders`] to a set of columns.
+///
+/// Columns sticking to the start are moved by the width of the `controls` leading control
+/// cells, which are sticky as well (see [`sticky_control`]).
+pub(crate) fn sticky_columns<'a, C>(
+    controls: usize,
+    columns: impl IntoIterator<Item = &'a TableColumnProperties<C>>,
+) -> Vec<Option<(StickyColumn, bool)>>
+where
+    C: Clone + Eq + 'static,
+{
+    let sticky = columns
+        .into_iter()
+        .map(|c| {
+            c.sticky.clone().map(|mut sticky| {
+                if sticky.position == StickyPosition::Start && controls > 0 {
+                    let width = controls * CONTROL_CELL_WIDTH;
+                    sticky.offset = Some(match sticky.offset {
+                        Some(offset) => format!("calc({width}rem + {offset})").into(),
+                        None => format!("{width}rem").into(),
+                    });
+                }
+                sticky
+            })
+        })
+        .collect::<Vec<_>>();
+    let borders = sticky_borders(
+        &sticky
+            .iter()
+            .map(|s| s.as_ref().map(|s| s.position))
+            .collect::<Vec<_>>(),
+    );
+
+    sticky
+        .into_iter()
+        .zip(borders)
+        .map(|(sticky, border)| sticky.map(|sticky| (sticky, border)))
+        .collect()
 }
 
 #[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
@@ -84,6 +279,13 @@ where
     K: Clone + Eq + 'static,
 {
     let table_header_context = use_context::<TableHeaderContext<K>>();
+    let resize_context = use_context::<ColumnResizeContext<K>>();
+
+    let node_ref = use_node_ref();
+    // the width while dragging the border
+    let dragging = use_state_eq(|| None::<u32>);
+    // the width, if the header doesn't keep track of it
+    let local = use_state_eq(|| None::<u32>);
 
     let mut class = classes!("pf-v5-c-table__th");
 
@@ -95,17 +297,59 @@ where
         class.push(classes!("pf-m-center"));
     }
 
-    if props.onsort.is_some() {
+    if props.is_sortable() {
         class.push(classes!("pf-v5-c-table__sort"));
     }
 
     class.extend_from(&props.width);
     class.extend_from(&props.text_modifier);
 
+    let mut style = props
+        .sticky
+        .as_ref()
+        .and_then(|sticky| sticky.apply(props.sticky_border, &mut class));
+
+    let handle = props.resizable.then(|| {
+        let width = match &resize_context {
+            Some(context) => context.widths.get(&props.index),
+            None => *local,
+        };
+        let width = dragging.or(width);
+
+        let style = style.get_or_insert_with(String::new);
+        if props.sticky.is_none() {
+            // sticky cells are positioned already
+            style.push_str("position: relative;");
+        }
+        if let Some(width) = width {
+            style.push_str(&format!("width: {width}px; min-width: {width}px;"));
+        }
+
+        let onresize = match &resize_context {
+            Some(context) => {
+                let index = props.index.clone();
+                context.onresize.reform(move |width| (index.clone(), width))
+            }
+            None => Callback::from(move |width| local.set(Some(width))),
+        };
+
+        html!(
+            <ResizeHandle
+                target={node_ref.clone()}
+                {width}
+                min={props.min_width}
+                label={props.label.clone()}
+                onmove={Callback::from(move |width| dragging.set(width))}
+                {onresize}
+            />
+        )
+    });
+
     match &props.label {
-        None => html! (<th></th>),
+        None if props.sticky.is_none() && handle.is_none() => html! (<th></th>),
+        None => html! (<th ref={node_ref} {class} {style}>{ handle }</th>),
         Some(label) => {
-            let th_content = if let Some(onsort) = &props.onsort {
+            let th_content = if props.is_sortable() {
                 let header_context = table_header_context.expect(
                     "Column must be inside TableHeader, the expected context is defined there",
                 );
@@ -145,18 +389,27 @@ where
                             {
                                 // Emit sorting in context and in user callback
                                 let onsort_context = header_context.onsort.clone();
-                                let onsort = onsort.clone();
+                                let onsort = props.onsort.clone();
+                                let onsortevent = props.onsortevent.clone();
 
                                 let index = props.index.clone();
                                 let order = sort_by_next_status.1;
 
-                                Callback::from(move |_| {
+                                Callback::from(move |evt: MouseEvent| {
                                     let sort_by = TableHeaderSortBy {
                                         index: index.clone(),
                                         order: !order
                                     };
                                     onsort_context.emit(sort_by.clone());
-                                    onsort.emit(sort_by.clone());
+                                    if let Some(onsort) = &onsort {
+                                        onsort.emit(sort_by.clone());
+                                    }
+                                    if let Some(onsortevent) = &onsortevent {
+                                        onsortevent.emit(TableHeaderSortEvent {
+                                            sort_by,
+                                            extend: evt.shift_key(),
+                                        });
+                                    }
                                 })
                             }
                         }
@@ -176,8 +429,9 @@ where
             };
 
             html!(
-                <th title={label.clone()} {class} scope="col" role="columnheader">
+                <th ref={node_ref} title={label.clone()} {class} {style} scope="col" role="columnheader">
                     {th_content}
+                    {handle}
                 </th>
             )
         }
@@ -200,4 +454,53 @@ mod test {
         assert_eq!(round(100), 90);
         assert_eq!(round(200), 90);
     }
+
+    #[test]
+    fn test_sticky_borders() {
+        use StickyPosition::*;
+
+        assert_eq!(sticky_borders(&[]), Vec::<bool>::new());
+        assert_eq!(sticky_borders(&[None, None]), vec![false, false]);
+        assert_eq!(
+            sticky_borders(&[Some(Start), Some(Start), None, None]),
+            vec![false, true, false, false]
+        );
+        assert_eq!(
+            sticky_borders(&[Some(Start), None, Some(End), Some(End)]),
+            vec![true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_sticky_controls() {
+        let columns = [
+            yew::props!(TableColumnProperties<usize> { index: 0, sticky: StickyColumn::start() }),
+            yew::props!(TableColumnProperties<usize> {
+                index: 1,
+                sticky: StickyColumn::start().offset("10rem"),
+            }),
+            yew::props!(TableColumnProperties<usize> { index: 2, sticky: StickyColumn::end() }),
+        ];
+
+        let sticky = sticky_columns(2, &columns);
+        let offsets = sticky
+            .iter()
+            .map(|s| s.as_ref().and_then(|(s, _)| s.offset.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            offsets,
+            vec![
+                Some(AttrValue::from("6rem")),
+                Some(AttrValue::from("calc(6rem + 10rem)")),
+                None
+            ]
+        );
+        assert_eq!(
+            sticky_control(1, &sticky).and_then(|s| s.offset),
+            Some(AttrValue::from("3rem"))
+        );
+
+        // without columns sticking to the start, controls don't stick either
+        assert_eq!(sticky_control(0, &sticky[2..]), None);
+    }
 }
@@ -1,4 +1,5 @@
-use crate::prelude::{Button, ButtonVariant};
+use crate::prelude::{Button, ButtonVariant, CheckboxState};
+use web_sys::{HtmlElement, HtmlInputElement};
 use yew::prelude::*;
 
 use super::*;
@@ -16,12 +17,22 @@ pub struct ComposableTableProperties {
     pub mode: TableMode,
     #[prop_or_default]
     pub sticky_header: bool,
+    /// Wrap the table in scroll containers, allowing it to scroll horizontally.
+    ///
+    /// This is required when using sticky columns.
+    #[prop_or_default]
+    pub scrollable: bool,
     #[prop_or_default]
     pub grid: Option<TableGridMode>,
     #[prop_or(true)]
     pub borders: bool,
     #[prop_or_default]
     pub id: AttrValue,
+    /// Only render the visible rows, using a scroll container.
+    ///
+    /// Also see [`use_table_virtualization`].
+    #[prop_or_default]
+    pub virtualization: Option<UseTableVirtualization>,
     /// OUIA Component id
     #[prop_or_default]
     pub ouia_id: Option<String>,
@@ -48,7 +59,11 @@ pub fn composable_table(props: &ComposableTableProperties) -> Html {
         id.clone().unwrap_or(OUIA.generated_id())
     });
     let mut class = classes!("pf-v5-c-table", props.class.clone());
-    if props.sticky_header {
+    let virtualization = props
+        .virtualization
+        .as_ref()
+        .filter(|virtualization| virtualization.is_enabled());
+    if props.sticky_header || virtualization.is_some() {
         class.push(classes!("pf-m-sticky-header"));
     }
     class.extend_from(&props.grid);
@@ -73,7 +88,7 @@ pub fn composable_table(props: &ComposableTableProperties) -> Html {
         TableMode::Default => {}
     }
 
-    html! {
+    let table = html! {
         <table
             id={&props.id}
             {class}
@@ -84,7 +99,41 @@ pub fn composable_table(props: &ComposableTableProperties) -> Html {
         >
             {props.children.clone()}
         </table>
+    };
 
+    let inner = match virtualization {
+        Some(virtualization) => {
+            let height = virtualization
+                .settings
+                .as_ref()
+                .map(|settings| settings.height.clone())
+                .unwrap_or_default();
+            html!(
+                <div
+                    class="pf-v5-c-scroll-inner-wrapper"
+                    ref={virtualization.container.clone()}
+                    onscroll={virtualization.onscroll.clone()}
+                    style={format!("height: {height}; overflow: auto;")}
+                >
+                    { table }
+                </div>
+            )
+        }
+        None if props.scrollable => html!(
+            <div class="pf-v5-c-scroll-inner-wrapper">
+                { table }
+            </div>
+        ),
+        None => table,
+    };
+
+    match props.scrollable {
+        true => html!(
+            <div class="pf-v5-c-scroll-outer-wrapper">
+                { inner }
+            </div>
+        ),
+        false => inner,
     }
 }
 
@@ -140,6 +189,9 @@ pub struct TableRowProperties {
     pub expanded: bool,
     #[prop_or_default]
     pub control_row: bool,
+    /// Make the row draggable, also see [`use_table_reorder`]
+    #[prop_or_default]
+    pub drag: Option<DragParams>,
 }
 
 #[function_component(TableRow)]
@@ -160,8 +212,21 @@ pub fn table_row(props: &TableRowProperties) -> Html {
     if props.control_row {
         class.push("pf-v5-c-table__control-row");
     }
+    if props.drag.as_ref().is_some_and(|drag| drag.dragged) {
+        class.push("pf-m-ghost-row");
+    }
+    let drag = props.drag.as_ref();
     html! {
-        <tr class={class.clone()} role="row" onclick={props.onclick.clone()}>
+        <tr
+            class={class.clone()}
+            role="row"
+            onclick={props.onclick.clone()}
+            draggable={drag.map(|_| "true")}
+            ondragstart={drag.map(|drag| drag.ondragstart.clone())}
+            ondragover={drag.map(|drag| drag.ondragover.clone())}
+            ondrop={drag.map(|drag| drag.ondrop.clone())}
+            ondragend={drag.map(|drag| drag.ondragend.clone())}
+        >
             {props.children.clone()}
         </tr>
     }
@@ -198,6 +263,129 @@ pub enum ExpandType {
     Column,
 }
 
+/// A request to change the selection of a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSelectEvent {
+    /// The new selection state of the row
+    pub selected: bool,
+    /// If the selection should be applied to the range from the last selected row (shift-click)
+    pub range: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectParams {
+    pub selected: bool,
+    pub disabled: bool,
+    pub onselect: Callback<RowSelectEvent>,
+}
+
+/// Parameters for the "select all" checkbox of a table header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectAllParams {
+    pub state: CheckboxState,
+    pub onselect: Callback<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub(crate) struct TableCheckProperties {
+    pub checked: CheckboxState,
+    #[prop_or_default]
+    pub disabled: bool,
+    pub aria_label: AttrValue,
+    pub onselect: Callback<RowSelectEvent>,
+}
+
+/// The checkbox of a selectable table row or header.
+#[function_component(TableCheck)]
+pub(crate) fn table_check(props: &TableCheckProperties) -> Html {
+    let node_ref = use_node_ref();
+
+    {
+        let node_ref = node_ref.clone();
+        let checked = props.checked;
+        use_effect(move || {
+            if let Some(elem) = node_ref.cast::<HtmlInputElement>() {
+                elem.set_indeterminate(checked == CheckboxState::Indeterminate)
+            }
+        });
+    }
+
+    let onclick = use_callback(
+        (props.onselect.clone(), node_ref.clone()),
+        |evt: MouseEvent, (onselect, node_ref)| {
+            evt.stop_propagation();
+            let selected = node_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| input.checked())
+                .unwrap_or_default();
+            onselect.emit(RowSelectEvent {
+                selected,
+                range: evt.shift_key(),
+            });
+        },
+    );
+
+    html!(
+        <label>
+            <input
+                ref={node_ref}
+                type="checkbox"
+                aria-label={props.aria_label.clone()}
+                disabled={props.disabled}
+                checked={props.checked == CheckboxState::Checked}
+                {onclick}
+            />
+        </label>
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub(crate) struct DragHandleProperties {
+    pub grabbed: bool,
+    pub onkeydown: Callback<KeyboardEvent>,
+    pub onblur: Callback<FocusEvent>,
+    /// Keep the handle out of the tab sequence, e.g. when the row itself is focusable
+    #[prop_or_default]
+    pub tabindex: Option<i32>,
+}
+
+/// The drag handle of a draggable row.
+#[function_component(DragHandle)]
+pub(crate) fn drag_handle(props: &DragHandleProperties) -> Html {
+    let node_ref = use_node_ref();
+
+    // moving the row drops the focus, restore it while the row is grabbed
+    {
+        let node_ref = node_ref.clone();
+        let grabbed = props.grabbed;
+        use_effect(move || {
+            let lost = gloo_utils::document()
+                .active_element()
+                .map_or(true, |active| {
+                    active.tag_name().eq_ignore_ascii_case("body")
+                });
+            if let Some(button) = node_ref.cast::<HtmlElement>().filter(|_| grabbed && lost) {
+                let _ = button.focus();
+            }
+        });
+    }
+
+    html!(
+        <button
+            ref={node_ref}
+            class="pf-v5-c-button pf-m-plain"
+            type="button"
+            aria-label="Reorder row"
+            aria-pressed={props.grabbed.to_string()}
+            tabindex={props.tabindex.map(|tabindex| tabindex.to_string())}
+            onkeydown={props.onkeydown.clone()}
+            onblur={props.onblur.clone()}
+        >
+            { Icon::GripVertical }
+        </button>
+    )
+}
+
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct TableDataProperties {
     #[prop_or_default]
@@ -210,6 +398,12 @@ pub struct TableDataProperties {
     pub text_modifier: Option<TextModifier>,
     #[prop_or_default]
     pub expandable: Option<ExpandParams>,
+    /// Render the cell as the selection checkbox of the row
+    #[prop_or_default]
+    pub select: Option<SelectParams>,
+    /// Render the cell as the drag handle of the row
+    #[prop_or_default]
+    pub drag: Option<DragParams>,
     #[prop_or_default]
     pub data_label: Option<AttrValue>,
     #[prop_or_default]
@@ -218,6 +412,12 @@ pub struct TableDataProperties {
     pub colspan: Option<usize>,
     #[prop_or_default]
     pub action: bool,
+    /// Render the cell as part of a sticky column
+    #[prop_or_default]
+    pub sticky: Option<StickyColumn>,
+    /// Render the border of a sticky column, separating it from the scrolling columns
+    #[prop_or_default]
+    pub sticky_border: bool,
 }
 
 #[function_component(TableData)]
@@ -232,6 +432,11 @@ pub fn table_data(props: &TableDataProperties) -> Html {
     class.extend_from(&props.text_modifier);
     class.extend_from(&props.span_modifiers);
 
+    let style = props
+        .sticky
+        .as_ref()
+        .and_then(|sticky| sticky.apply(props.sticky_border, &mut class));
+
     let mut content = props.children.clone();
     if let Some(expandable) = props.expandable.as_ref() {
         let onclick = {
@@ -276,9 +481,32 @@ pub fn table_data(props: &TableDataProperties) -> Html {
         };
     }
 
+    if let Some(select) = props.select.as_ref() {
+        class.push("pf-v5-c-table__check");
+        content = html! {
+            <TableCheck
+                checked={CheckboxState::from(select.selected)}
+                disabled={select.disabled}
+                aria_label="Select row"
+                onselect={select.onselect.clone()}
+            />
+        };
+    }
+
+    if let Some(drag) = props.drag.as_ref() {
+        class.push("pf-v5-c-table__draggable");
+        content = html! {
+            <DragHandle
+                grabbed={drag.grabbed}
+                onkeydown={drag.onkeydown.clone()}
+                onblur={drag.onblur.clone()}
+            />
+        };
+    }
+
     let colspan = props.colspan.as_ref().map(|cols| cols.to_string());
     html! {
-        <td {class} role="cell" data-label={props.data_label.clone()} {colspan}>
+        <td {class} {style} role="cell" data-label={props.data_label.clone()} {colspan}>
             { content }
         </td>
     }
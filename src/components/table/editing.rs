@@ -0,0 +1,291 @@
+use super::{Cell, EditCellContext, TableEntryEditor, TableModel};
+use crate::prelude::{InputState, ValidationContext, ValidationResult, Validator};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A change to the item being edited.
+pub(crate) type Update<T> = Box<dyn FnOnce(&mut T)>;
+
+/// The editing state of a [`super::Table`], for its model `M`.
+pub type TableEditing<C, M> =
+    UseTableEditing<C, <M as TableModel<C>>::Item, <M as TableModel<C>>::Key>;
+
+/// The row currently being edited.
+struct Draft<K, T> {
+    key: K,
+    item: T,
+    /// If the item was changed since editing started
+    dirty: bool,
+}
+
+enum EditAction<K, T> {
+    Start(K, T),
+    Update(Update<T>),
+    Stop,
+}
+
+struct EditState<K, T> {
+    draft: Option<Draft<K, T>>,
+}
+
+impl<K, T> Default for EditState<K, T> {
+    fn default() -> Self {
+        Self { draft: None }
+    }
+}
+
+impl<K, T> Reducible for EditState<K, T>
+where
+    K: Clone,
+    T: Clone,
+{
+    type Action = EditAction<K, T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            EditAction::Start(key, item) => Rc::new(Self {
+                draft: Some(Draft {
+                    key,
+                    item,
+                    dirty: false,
+                }),
+            }),
+            EditAction::Update(update) => match &self.draft {
+                Some(draft) => {
+                    let mut item = draft.item.clone();
+                    update(&mut item);
+                    Rc::new(Self {
+                        draft: Some(Draft {
+                            key: draft.key.clone(),
+                            item,
+                            dirty: true,
+                        }),
+                    })
+                }
+                // nothing to update
+                None => self,
+            },
+            EditAction::Stop => Rc::new(Self::default()),
+        }
+    }
+}
+
+/// The result of [`use_table_editing`].
+pub struct UseTableEditing<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    state: UseReducerHandle<EditState<K, T>>,
+    onsave: Callback<(K, T)>,
+    render: fn(&T, EditCellContext<'_, C, T>) -> Cell,
+    validator: fn(&T, &C) -> Validator<T, ValidationResult>,
+}
+
+impl<C, T, K> Clone for UseTableEditing<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            onsave: self.onsave.clone(),
+            render: self.render,
+            validator: self.validator,
+        }
+    }
+}
+
+impl<C, T, K> PartialEq for UseTableEditing<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(&*self.state, &*other.state) && self.onsave == other.onsave
+    }
+}
+
+impl<C, T, K> UseTableEditing<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    /// Check if a row is being edited.
+    pub fn is_editing(&self, key: &K) -> bool {
+        self.state
+            .draft
+            .as_ref()
+            .is_some_and(|draft| &draft.key == key)
+    }
+
+    /// Get the key of the row being edited.
+    pub fn editing(&self) -> Option<K> {
+        self.state.draft.as_ref().map(|draft| draft.key.clone())
+    }
+
+    /// Start editing a row, discarding any other pending edit.
+    pub fn edit(&self, key: K, item: T) {
+        self.state.dispatch(EditAction::Start(key, item));
+    }
+
+    /// Stop editing, discarding all changes.
+    pub fn cancel(&self) {
+        self.state.dispatch(EditAction::Stop);
+    }
+
+    /// Validate a column of the row being edited.
+    pub fn validate(&self, column: &C) -> ValidationResult {
+        let Some(draft) = &self.state.draft else {
+            return ValidationResult::ok();
+        };
+
+        (self.validator)(&draft.item, column)
+            .run(ValidationContext {
+                value: draft.item.clone(),
+                initial: !draft.dirty,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Check if the row being edited is valid, for all provided columns.
+    pub fn is_valid<'a>(&self, columns: impl IntoIterator<Item = &'a C>) -> bool {
+        columns
+            .into_iter()
+            .all(|column| self.validate(column).state != InputState::Error)
+    }
+
+    /// Save the row being edited, if all provided columns are valid.
+    ///
+    /// Returns `true` if the row was saved.
+    pub fn save<'a>(&self, columns: impl IntoIterator<Item = &'a C>) -> bool {
+        let Some(draft) = &self.state.draft else {
+            return false;
+        };
+        if !self.is_valid(columns) {
+            return false;
+        }
+
+        self.onsave.emit((draft.key.clone(), draft.item.clone()));
+        self.state.dispatch(EditAction::Stop);
+        true
+    }
+
+    /// Render the edit widget of a column, for the row being edited.
+    pub(crate) fn render_cell(&self, column: &C) -> Option<Cell> {
+        let draft = self.state.draft.as_ref()?;
+
+        let state = self.state.clone();
+        let context = EditCellContext {
+            column,
+            validation: self.validate(column),
+            onupdate: Callback::from(move |update| state.dispatch(EditAction::Update(update))),
+        };
+
+        Some((self.render)(&draft.item, context))
+    }
+}
+
+/// Edit the rows of a [`super::Table`], one at a time.
+///
+/// Hand over the result to the `editing` property of the table. Each row gets an "edit" action,
+/// switching the row into edit mode. While editing, the cells are rendered using
+/// [`TableEntryEditor::render_edit_cell`] and validated using [`TableEntryEditor::validator`].
+/// Saving a row is only possible when no column reports an error, in which case `onsave` is
+/// called with the key and the edited item.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name };
+/// #[derive(Clone, PartialEq)]
+/// struct ExampleEntry { name: String };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///     }
+///   }
+/// }
+///
+/// impl TableEntryEditor<Column> for ExampleEntry {
+///   fn render_edit_cell(&self, context: EditCellContext<'_, Column, Self>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(
+///         <TextInput
+///           value={self.name.clone()}
+///           state={context.validation.state}
+///           onchange={context.update(|entry: &mut Self, value: String| entry.name = value)}
+///         />
+///       ).into(),
+///     }
+///   }
+///
+///   fn validator(&self, column: &Column) -> Validator<Self, ValidationResult> {
+///     match column {
+///       Column::Name => Validator::from(|ctx: ValidationContext<Self>| {
+///         match ctx.value.name.is_empty() {
+///           true => ValidationResult::error("Must not be empty"),
+///           false => ValidationResult::ok(),
+///         }
+///       }),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let entries = use_state(|| Rc::new(vec![ExampleEntry { name: "foo".into() }]));
+///
+///   let onsave = use_callback(entries.clone(), |(index, entry): (usize, ExampleEntry), entries| {
+///     let mut next = (***entries).clone();
+///     next[index] = entry;
+///     entries.set(Rc::new(next));
+///   });
+///   let editing = use_table_editing(onsave);
+///
+///   let (entries, onexpand) = use_table_data(MemoizedTableModel::new((*entries).clone()));
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <Table<Column, UseTableData<Column, MemoizedTableModel<ExampleEntry>>>
+///       {header}
+///       {entries}
+///       {onexpand}
+///       {editing}
+///     />
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_editing<C, T, K>(onsave: Callback<(K, T)>) -> UseTableEditing<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryEditor<C> + Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    let state = use_reducer(EditState::<K, T>::default);
+
+    UseTableEditing {
+        state,
+        onsave,
+        render: T::render_edit_cell,
+        validator: T::validator,
+    }
+}
@@ -0,0 +1,445 @@
+use super::{TableDataModel, TableHeader, TableModel, TableSelection};
+use gloo_timers::callback::Timeout;
+use gloo_utils::{body, document};
+use std::hash::Hash;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use yew::virtual_dom::VChild;
+
+/// The format of an export.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableExportFormat {
+    /// Comma separated values, according to RFC 4180.
+    #[default]
+    Csv,
+    /// A JSON array, containing an object per row, using the column labels as keys.
+    ///
+    /// Columns without a label use their position in the header as key. Duplicate keys get a
+    /// numeric suffix.
+    Json,
+}
+
+impl TableExportFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    /// Let the browser download exported content, using a Blob URL.
+    pub fn download(&self, filename: &str, content: &str) -> Result<(), JsValue> {
+        let parts = js_sys::Array::of1(&JsValue::from_str(content));
+        let options = BlobPropertyBag::new();
+        options.set_type(self.mime_type());
+        let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+
+        let url = Url::create_object_url_with_blob(&blob)?;
+
+        let anchor = match document()
+            .create_element("a")
+            .and_then(|element| Ok(element.dyn_into::<HtmlAnchorElement>()?))
+        {
+            Ok(anchor) => anchor,
+            Err(err) => {
+                Url::revoke_object_url(&url)?;
+                return Err(err);
+            }
+        };
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.set_hidden(true);
+
+        // some browsers only follow links which are part of the document
+        let result = body().append_child(&anchor).map(|_| anchor.click());
+
+        // let the browser start the download before cleaning up
+        Timeout::new(0, move || {
+            anchor.remove();
+            let _ = Url::revoke_object_url(&url);
+        })
+        .forget();
+
+        result
+    }
+}
+
+/// A function extracting the text of a cell.
+pub type TableTextExtractor<T> = Rc<dyn Fn(&T) -> String>;
+
+/// Export the content of a table as text.
+///
+/// As the rendered cells of a table can't be serialized, each exported column requires a text
+/// extractor. The columns are exported in the order, and using the labels, of a
+/// [`TableHeader`]. Columns of the header without an extractor are skipped.
+///
+/// Which rows get exported depends on the model handed over:
+///
+/// * [`TableExport::visible`] exports the rows of a [`TableModel`], as shown by the table.
+/// * [`TableExport::all`] exports all rows of a [`TableDataModel`], e.g. the data before
+///   filtering or paging it.
+/// * [`TableExport::selected`] exports the rows of a [`TableDataModel`] which are selected.
+///
+/// The result can then be offered for download using [`TableExportFormat::download`].
+pub struct TableExport<C, T> {
+    extractors: Vec<(C, TableTextExtractor<T>)>,
+}
+
+impl<C, T> Default for TableExport<C, T> {
+    fn default() -> Self {
+        Self {
+            extractors: Vec::new(),
+        }
+    }
+}
+
+impl<C, T> Clone for TableExport<C, T>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            extractors: self.extractors.clone(),
+        }
+    }
+}
+
+impl<C, T> TableExport<C, T>
+where
+    C: Clone + Eq + 'static,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a text extractor for a column.
+    ///
+    /// Adding an extractor for a column a second time replaces the previous one.
+    pub fn with<F>(mut self, column: C, f: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.extractors.retain(|(c, _)| c != &column);
+        self.extractors.push((column, Rc::new(f)));
+        self
+    }
+
+    fn get(&self, column: &C) -> Option<&TableTextExtractor<T>> {
+        self.extractors
+            .iter()
+            .find_map(|(c, f)| (c == column).then_some(f))
+    }
+
+    /// The exported columns: position in the header, label, and extractor, in the order of
+    /// the header.
+    fn columns(
+        &self,
+        header: &VChild<TableHeader<C>>,
+    ) -> Vec<(usize, Option<String>, TableTextExtractor<T>)> {
+        header
+            .props
+            .children
+            .iter()
+            .enumerate()
+            .filter_map(|(position, column)| {
+                let f = self.get(&column.props.index)?;
+                Some((position, column.props.label.clone(), f.clone()))
+            })
+            .collect()
+    }
+
+    /// Export a set of items.
+    pub fn export<'a>(
+        &self,
+        format: TableExportFormat,
+        header: &VChild<TableHeader<C>>,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> String
+    where
+        T: 'a,
+    {
+        let columns = self.columns(header);
+        let rows = items
+            .into_iter()
+            .map(|item| columns.iter().map(|(_, _, f)| f(item)).collect::<Vec<_>>());
+
+        match format {
+            TableExportFormat::Csv => {
+                let labels = columns
+                    .iter()
+                    .map(|(_, label, _)| label.clone().unwrap_or_default())
+                    .collect::<Vec<_>>();
+                to_csv(&labels, rows)
+            }
+            TableExportFormat::Json => {
+                let keys = json_keys(
+                    columns
+                        .iter()
+                        .map(|(position, label, _)| (*position, label)),
+                );
+                to_json(&keys, rows)
+            }
+        }
+    }
+
+    /// Export the rows of a table model, as shown by the table.
+    pub fn visible<M>(
+        &self,
+        format: TableExportFormat,
+        header: &VChild<TableHeader<C>>,
+        model: &M,
+    ) -> String
+    where
+        M: TableModel<C, Item = T>,
+    {
+        self.export(format, header, model.iter().map(|entry| entry.value))
+    }
+
+    /// Export all rows of a data model.
+    pub fn all<M>(
+        &self,
+        format: TableExportFormat,
+        header: &VChild<TableHeader<C>>,
+        model: &M,
+    ) -> String
+    where
+        M: TableDataModel<C, Item = T>,
+    {
+        self.export(format, header, model.iter().map(|(_, item)| item))
+    }
+
+    /// Export the selected rows of a data model, in the order of the model.
+    pub fn selected<M>(
+        &self,
+        format: TableExportFormat,
+        header: &VChild<TableHeader<C>>,
+        model: &M,
+        selection: &TableSelection<M::Key>,
+    ) -> String
+    where
+        M: TableDataModel<C, Item = T>,
+        M::Key: Hash,
+    {
+        self.export(
+            format,
+            header,
+            model
+                .iter()
+                .filter(|(key, _)| selection.is_selected(key))
+                .map(|(_, item)| item),
+        )
+    }
+}
+
+/// Escape a single CSV field, quoting it if necessary.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) || value.starts_with(' ') || value.ends_with(' ') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render a header line and rows as CSV.
+fn to_csv<R, V>(labels: &[String], rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = V>,
+    V: AsRef<str>,
+{
+    fn line<V: AsRef<str>>(result: &mut String, values: impl IntoIterator<Item = V>) {
+        let values = values
+            .into_iter()
+            .map(|value| csv_field(value.as_ref()))
+            .collect::<Vec<_>>();
+        result.push_str(&values.join(","));
+        result.push_str("\r\n");
+    }
+
+    let mut result = String::new();
+    line(&mut result, labels);
+    for row in rows {
+        line(&mut result, row);
+    }
+    result
+}
+
+/// Create unique keys for JSON objects, from the position and label of the columns.
+///
+/// Columns without a label use their position as key, duplicates get a numeric suffix.
+fn json_keys<'a>(columns: impl IntoIterator<Item = (usize, &'a Option<String>)>) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for (position, label) in columns {
+        let base = match label {
+            Some(label) => label.clone(),
+            None => position.to_string(),
+        };
+        let mut key = base.clone();
+        let mut n = 1;
+        while keys.contains(&key) {
+            n += 1;
+            key = format!("{base} ({n})");
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+/// A row as JSON object, keeping the order of its fields.
+struct JsonRow<'a>(Vec<(&'a str, String)>);
+
+impl serde::Serialize for JsonRow<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, value)| (key, value)))
+    }
+}
+
+/// Render rows as JSON array of objects, using the labels as keys, in the order of the labels.
+fn to_json<R, V>(labels: &[String], rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = V>,
+    V: Into<String>,
+{
+    let rows = rows
+        .into_iter()
+        .map(|row| {
+            JsonRow(
+                labels
+                    .iter()
+                    .map(String::as_str)
+                    .zip(row.into_iter().map(Into::into))
+                    .collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&rows).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::{Cell, CellContext, TableColumn, TableEntryRenderer};
+    use yew::{html, html_nested};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Column {
+        Name,
+        Description,
+        Hidden,
+    }
+
+    #[derive(Clone)]
+    struct Item(&'static str, &'static str);
+
+    impl TableEntryRenderer<Column> for Item {
+        fn render_cell(&self, _context: CellContext<'_, Column>) -> Cell {
+            html!().into()
+        }
+    }
+
+    fn header() -> VChild<TableHeader<Column>> {
+        html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> label="Name" index={Column::Name} />
+                <TableColumn<Column> label="Hidden" index={Column::Hidden} />
+                <TableColumn<Column> label="Description" index={Column::Description} />
+            </TableHeader<Column>>
+        )
+    }
+
+    fn export() -> TableExport<Column, Item> {
+        TableExport::new()
+            .with(Column::Description, |item: &Item| item.1.to_string())
+            .with(Column::Name, |item: &Item| item.0.to_string())
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item("foo", "simple"),
+            Item("bar", "with, comma"),
+            Item("baz", "with \"quotes\"\nand a new line"),
+        ]
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("foo"), "foo");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+        assert_eq!(csv_field(" a"), "\" a\"");
+        assert_eq!(csv_field("a\r\nb"), "\"a\r\nb\"");
+    }
+
+    #[test]
+    fn test_csv() {
+        let items = items();
+        assert_eq!(
+            export().export(TableExportFormat::Csv, &header(), &items),
+            "Name,Description\r\nfoo,simple\r\nbar,\"with, comma\"\r\nbaz,\"with \"\"quotes\"\"\nand a new line\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let items = items();
+        let json = export().export(TableExportFormat::Json, &header(), &items[0..2]);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!([
+                { "Name": "foo", "Description": "simple" },
+                { "Name": "bar", "Description": "with, comma" },
+            ])
+        );
+        // the keys follow the order of the header
+        assert_eq!(
+            json,
+            r#"[{"Name":"foo","Description":"simple"},{"Name":"bar","Description":"with, comma"}]"#
+        );
+    }
+
+    #[test]
+    fn test_json_keys() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> label="Name" index={Column::Name} />
+                <TableColumn<Column> index={Column::Hidden} />
+                <TableColumn<Column> label="Name" index={Column::Description} />
+            </TableHeader<Column>>
+        );
+        let export = export().with(Column::Hidden, |item: &Item| item.0.len().to_string());
+
+        let json = export.export(TableExportFormat::Json, &header, &items()[0..1]);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!([
+                { "Name": "foo", "1": "3", "Name (2)": "simple" },
+            ])
+        );
+
+        assert_eq!(
+            json_keys([(0, &Some("1".to_string())), (1, &None), (2, &None)]),
+            vec!["1", "1 (2)", "2"]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            export().export(TableExportFormat::Csv, &header(), &[]),
+            "Name,Description\r\n"
+        );
+        assert_eq!(
+            export().export(TableExportFormat::Json, &header(), &[]),
+            "[]"
+        );
+    }
+}
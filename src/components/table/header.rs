@@ -1,6 +1,9 @@
-use super::column::TableColumn;
+use super::column::{sticky_columns, sticky_control, TableColumn};
+use super::composable::{RowSelectEvent, SelectAllParams, TableCheck};
+use super::resize::{ColumnResizeContext, TableColumnWidths};
 use crate::core::Order;
 use std::fmt::Debug;
+use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Copy)]
@@ -31,6 +34,19 @@ where
     }
 }
 
+/// A sort request emitted by a [`TableColumn`].
+///
+/// In addition to the requested sort order, this carries the information if the request should
+/// extend the current sort criteria (e.g. when shift-clicking a column) instead of replacing it.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub struct TableHeaderSortEvent<K>
+where
+    K: Clone + Eq,
+{
+    pub sort_by: TableHeaderSortBy<K>,
+    pub extend: bool,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TableHeaderContext<K>
 where
@@ -52,8 +68,25 @@ where
     pub children: ChildrenWithProps<TableColumn<K>>,
     #[prop_or_default]
     pub(crate) expandable: bool,
+    /// Render a column for the inline edit actions
+    #[prop_or_default]
+    pub(crate) editable: bool,
     #[prop_or_default]
     pub hide_actions: bool,
+    /// Render an empty column for the drag handles of draggable rows
+    #[prop_or_default]
+    pub draggable: bool,
+    /// The widths of resizable columns, controlling their widths.
+    ///
+    /// If not set, the header keeps track of the widths itself.
+    #[prop_or_default]
+    pub widths: Option<TableColumnWidths<K>>,
+    /// Called with the widths of all resized columns, when the user resized a column.
+    #[prop_or_default]
+    pub onresize: Callback<TableColumnWidths<K>>,
+    /// Render a "select all" checkbox column
+    #[prop_or_default]
+    pub select_all: Option<SelectAllParams>,
 }
 
 /// The Table Header component.
@@ -76,19 +109,97 @@ where
         sortby: (*sortby).clone(),
     };
 
+    let widths = use_state_eq(TableColumnWidths::default);
+    let resize_context = {
+        let current = props.widths.clone().unwrap_or_else(|| (*widths).clone());
+        let onresize = use_callback(
+            (current.clone(), widths, props.onresize.clone()),
+            |(index, width), (current, widths, onresize)| {
+                let mut next = current.clone();
+                next.set(index, width);
+                widths.set(next.clone());
+                onresize.emit(next);
+            },
+        );
+        ColumnResizeContext {
+            widths: current,
+            onresize,
+        }
+    };
+
+    // let sticky columns know their offset, and if they need a border
+    let controls = [
+        props.expandable,
+        props.draggable,
+        props.select_all.is_some(),
+    ]
+    .into_iter()
+    .filter(|control| *control)
+    .count();
+    let children = props.children.iter().collect::<Vec<_>>();
+    let sticky = sticky_columns(controls, children.iter().map(|c| c.props.as_ref()));
+
+    // leading control cells stick along with them
+    let control = |n: usize, mut class: Classes| {
+        let style = sticky_control(n, &sticky).and_then(|sticky| sticky.apply(false, &mut class));
+        (class, style)
+    };
+    let (toggle_class, toggle_style) =
+        control(0, classes!("pf-v5-c-table__td", "pf-v5-c-table__toggle"));
+    let (drag_class, drag_style) = control(
+        props.expandable as usize,
+        classes!("pf-v5-c-table__td", "pf-v5-c-table__draggable"),
+    );
+    let (check_class, check_style) = control(
+        props.expandable as usize + props.draggable as usize,
+        classes!("pf-v5-c-table__th", "pf-v5-c-table__check"),
+    );
+
+    let children = children
+        .into_iter()
+        .zip(sticky.clone())
+        .map(|(mut column, sticky)| {
+            if let Some((sticky, border)) = sticky {
+                let props = Rc::make_mut(&mut column.props);
+                props.sticky = Some(sticky);
+                props.sticky_border = border;
+            }
+            column
+        });
+
     html! (
         <thead class="pf-v5-c-table__thead">
 
             <tr class="pf-v5-c-table__tr" role="row">
 
                 if props.expandable {
-                    <td class="pf-v5-c-table__td pf-v5-c-table__toggle" role="cell"></td>
+                    <td class={toggle_class} style={toggle_style} role="cell"></td>
+                }
+
+                if props.draggable {
+                    <td class={drag_class} style={drag_style} role="cell"></td>
+                }
+
+                if let Some(select_all) = &props.select_all {
+                    <th class={check_class} style={check_style} scope="col">
+                        <TableCheck
+                            checked={select_all.state}
+                            aria_label="Select all rows"
+                            onselect={select_all.onselect.reform(|evt: RowSelectEvent| evt.selected)}
+                        />
+                    </th>
                 }
 
                 <ContextProvider<TableHeaderContext<K>> context={table_header_context}>
-                    { for props.children.iter() }
+                    <ContextProvider<ColumnResizeContext<K>> context={resize_context}>
+                        { for children }
+                    </ContextProvider<ColumnResizeContext<K>>>
                 </ContextProvider<TableHeaderContext<K>>>
 
+                if props.editable {
+                    <td class="pf-v5-c-table__td"></td>
+                }
+
                 if !props.hide_actions {
                     <td class="pf-v5-c-table__td"></td>
                 }
@@ -0,0 +1,378 @@
+use crate::prelude::{Button, ButtonVariant, Checkbox, CheckboxState, Icon, TableHeader};
+use yew::{html::ChildrenRenderer, prelude::*, virtual_dom::VChild};
+
+/// An entry of a [`TableColumnLayout`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TableColumnLayoutEntry<C> {
+    pub index: C,
+    pub visible: bool,
+}
+
+/// The order and visibility of the columns of a table.
+///
+/// Columns of a table which are not part of the layout will be shown after the columns of the
+/// layout. Columns of the layout which are not part of the table are ignored. This allows
+/// persisting the layout, even if the columns of the table change later on.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TableColumnLayout<C> {
+    pub columns: Vec<TableColumnLayoutEntry<C>>,
+}
+
+impl<C> Default for TableColumnLayout<C> {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl<C> TableColumnLayout<C>
+where
+    C: Clone + Eq + 'static,
+{
+    /// Create a layout, using all columns of the header, in their original order.
+    pub fn from_header(header: &VChild<TableHeader<C>>) -> Self {
+        Self::from_columns(header.props.children.iter().map(|c| c.props.index.clone()))
+    }
+
+    /// Create a layout, all columns being visible.
+    pub fn from_columns(columns: impl IntoIterator<Item = C>) -> Self {
+        Self {
+            columns: columns
+                .into_iter()
+                .map(|index| TableColumnLayoutEntry {
+                    index,
+                    visible: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn position(&self, index: &C) -> Option<usize> {
+        self.columns.iter().position(|c| &c.index == index)
+    }
+
+    /// Check if a column is visible. Columns not being part of the layout are visible.
+    pub fn is_visible(&self, index: &C) -> bool {
+        self.columns
+            .iter()
+            .find(|c| &c.index == index)
+            .map_or(true, |c| c.visible)
+    }
+
+    /// Count the number of visible columns of the layout.
+    pub fn visible_count(&self) -> usize {
+        self.columns.iter().filter(|c| c.visible).count()
+    }
+
+    /// Show or hide a column, adding it to the layout if necessary.
+    pub fn set_visible(&mut self, index: C, visible: bool) {
+        match self.position(&index) {
+            Some(pos) => self.columns[pos].visible = visible,
+            None => self.columns.push(TableColumnLayoutEntry { index, visible }),
+        }
+    }
+
+    /// Move a column from one position to another.
+    pub fn move_to(&mut self, from: usize, to: usize) {
+        if from >= self.columns.len() {
+            return;
+        }
+        let entry = self.columns.remove(from);
+        let to = to.min(self.columns.len());
+        self.columns.insert(to, entry);
+    }
+
+    /// Move a column one position up (towards the start).
+    ///
+    /// Columns for which `rendered` returns `false` (e.g. columns which are not part of the
+    /// current header) are skipped.
+    pub fn move_up<F>(&mut self, index: &C, rendered: F)
+    where
+        F: Fn(&C) -> bool,
+    {
+        if let Some(pos) = self.position(index) {
+            if let Some(other) = (0..pos).rev().find(|n| rendered(&self.columns[*n].index)) {
+                self.columns.swap(pos, other);
+            }
+        }
+    }
+
+    /// Move a column one position down (towards the end).
+    ///
+    /// Columns for which `rendered` returns `false` (e.g. columns which are not part of the
+    /// current header) are skipped.
+    pub fn move_down<F>(&mut self, index: &C, rendered: F)
+    where
+        F: Fn(&C) -> bool,
+    {
+        if let Some(pos) = self.position(index) {
+            if let Some(other) =
+                (pos + 1..self.columns.len()).find(|n| rendered(&self.columns[*n].index))
+            {
+                self.columns.swap(pos, other);
+            }
+        }
+    }
+
+    /// Apply the layout to a set of columns, returning the visible columns in order.
+    pub fn apply<T, F>(&self, columns: impl IntoIterator<Item = T>, f: F) -> Vec<T>
+    where
+        F: Fn(&T) -> &C,
+    {
+        let mut columns = columns.into_iter().map(Some).collect::<Vec<_>>();
+        let mut result = Vec::with_capacity(columns.len());
+
+        for entry in &self.columns {
+            if let Some(column) = columns
+                .iter_mut()
+                .find(|c| c.as_ref().is_some_and(|c| f(c) == &entry.index))
+                .and_then(Option::take)
+            {
+                if entry.visible {
+                    result.push(column);
+                }
+            }
+        }
+
+        // remaining columns, not part of the layout
+        result.extend(columns.into_iter().flatten());
+
+        result
+    }
+
+    /// Apply the layout to a table header.
+    pub fn apply_header(&self, header: &VChild<TableHeader<C>>) -> VChild<TableHeader<C>> {
+        let mut header = header.clone();
+        let columns = self.apply(header.props.children.iter(), |c| &c.props.index);
+        std::rc::Rc::make_mut(&mut header.props).children = ChildrenRenderer::new(columns);
+        header
+    }
+}
+
+/// Properties for [`TableColumnManagement`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TableColumnManagementProperties<C>
+where
+    C: Clone + Eq + 'static,
+{
+    /// The header, providing the available columns
+    pub header: VChild<TableHeader<C>>,
+
+    /// The current layout
+    pub layout: TableColumnLayout<C>,
+
+    /// Called when the layout was changed
+    #[prop_or_default]
+    pub onchange: Callback<TableColumnLayout<C>>,
+}
+
+/// Manage the visibility and order of the columns of a table.
+///
+/// This lists all columns of a [`TableHeader`], allowing the user to show/hide them as well as
+/// changing their order. The result can be applied to a [`super::Table`] using its `column_layout`
+/// property.
+///
+/// The component is intended to be shown in a [`crate::prelude::Modal`] or [`crate::prelude::Popover`].
+///
+/// ## Properties
+///
+/// Defined by [`TableColumnManagementProperties`].
+#[function_component(TableColumnManagement)]
+pub fn table_column_management<C>(props: &TableColumnManagementProperties<C>) -> Html
+where
+    C: Clone + Eq + 'static,
+{
+    // all columns, including the ones not known to the layout
+    let layout = {
+        let mut layout = props.layout.clone();
+        for column in props.header.props.children.iter() {
+            if layout.position(&column.props.index).is_none() {
+                layout.set_visible(column.props.index.clone(), true);
+            }
+        }
+        layout
+    };
+
+    // the columns of the header, in the order of the layout
+    let columns = layout
+        .columns
+        .iter()
+        .filter_map(|entry| {
+            props
+                .header
+                .props
+                .children
+                .iter()
+                .find(|c| c.props.index == entry.index)
+                .map(|c| (c.props.index.clone(), c.props.label.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    let last = columns.len().saturating_sub(1);
+    let rendered = {
+        let header = props.header.clone();
+        move |index: &C| {
+            header
+                .props
+                .children
+                .iter()
+                .any(|c| &c.props.index == index)
+        }
+    };
+    let single_visible = layout.visible_count() <= 1;
+
+    html!(
+        <ul class="pf-v5-c-data-list pf-m-compact" role="list" aria-label="Table column management">
+            { for columns.into_iter().enumerate().map(|(n, (index, label))| {
+                let visible = layout.is_visible(&index);
+
+                let onchange = {
+                    let layout = layout.clone();
+                    let onchange = props.onchange.clone();
+                    let index = index.clone();
+                    Callback::from(move |state: CheckboxState| {
+                        let mut layout = layout.clone();
+                        layout.set_visible(index.clone(), state.into());
+                        onchange.emit(layout);
+                    })
+                };
+                let onup = {
+                    let layout = layout.clone();
+                    let rendered = rendered.clone();
+                    let onchange = props.onchange.clone();
+                    let index = index.clone();
+                    Callback::from(move |_| {
+                        let mut layout = layout.clone();
+                        layout.move_up(&index, &rendered);
+                        onchange.emit(layout);
+                    })
+                };
+                let ondown = {
+                    let layout = layout.clone();
+                    let rendered = rendered.clone();
+                    let onchange = props.onchange.clone();
+                    let index = index.clone();
+                    Callback::from(move |_| {
+                        let mut layout = layout.clone();
+                        layout.move_down(&index, &rendered);
+                        onchange.emit(layout);
+                    })
+                };
+
+                let label = label.unwrap_or_default();
+
+                html!(
+                    <li class="pf-v5-c-data-list__item">
+                        <div class="pf-v5-c-data-list__item-row">
+                            <div class="pf-v5-c-data-list__item-control">
+                                <div class="pf-v5-c-data-list__check">
+                                    <Checkbox
+                                        checked={visible}
+                                        disabled={visible && single_visible}
+                                        aria_label={format!("Show column {label}")}
+                                        {onchange}
+                                    />
+                                </div>
+                            </div>
+                            <div class="pf-v5-c-data-list__item-content">
+                                <div class="pf-v5-c-data-list__cell">
+                                    { label.clone() }
+                                </div>
+                            </div>
+                            <div class="pf-v5-c-data-list__item-action">
+                                <Button
+                                    variant={ButtonVariant::Plain}
+                                    icon={Icon::AngleUp}
+                                    disabled={n == 0}
+                                    aria_label={format!("Move column {label} up")}
+                                    onclick={onup}
+                                />
+                                <Button
+                                    variant={ButtonVariant::Plain}
+                                    icon={Icon::AngleDown}
+                                    disabled={n == last}
+                                    aria_label={format!("Move column {label} down")}
+                                    onclick={ondown}
+                                />
+                            </div>
+                        </div>
+                    </li>
+                )
+            }) }
+        </ul>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn apply(
+        layout: &TableColumnLayout<&'static str>,
+        columns: &[&'static str],
+    ) -> Vec<&'static str> {
+        layout.apply(columns.iter().copied(), |c| c)
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut layout = TableColumnLayout::from_columns(["a", "b", "c"]);
+        assert_eq!(apply(&layout, &["a", "b", "c"]), vec!["a", "b", "c"]);
+
+        layout.move_down(&"a", |_| true);
+        assert_eq!(apply(&layout, &["a", "b", "c"]), vec!["b", "a", "c"]);
+
+        layout.set_visible("c", false);
+        assert_eq!(apply(&layout, &["a", "b", "c"]), vec!["b", "a"]);
+        assert_eq!(layout.visible_count(), 2);
+
+        layout.move_to(0, 2);
+        assert_eq!(apply(&layout, &["a", "b", "c"]), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_unknown_columns() {
+        let layout = TableColumnLayout::from_columns(["c", "x", "a"]);
+        // "b" is not part of the layout, "x" is not part of the table
+        assert_eq!(apply(&layout, &["a", "b", "c"]), vec!["c", "a", "b"]);
+        assert!(layout.is_visible(&"b"));
+    }
+
+    #[test]
+    fn test_move_bounds() {
+        let mut layout = TableColumnLayout::from_columns(["a", "b"]);
+        layout.move_up(&"a", |_| true);
+        layout.move_down(&"b", |_| true);
+        layout.move_to(5, 0);
+        assert_eq!(apply(&layout, &["a", "b"]), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_move_skips_unrendered() {
+        let mut layout = TableColumnLayout::from_columns(["a", "x", "b"]);
+        let rendered = |c: &&str| *c != "x";
+
+        layout.move_down(&"a", rendered);
+        assert_eq!(apply(&layout, &["a", "b"]), vec!["b", "a"]);
+
+        layout.move_up(&"a", rendered);
+        assert_eq!(apply(&layout, &["a", "b"]), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut layout = TableColumnLayout::from_columns(["a".to_string(), "b".to_string()]);
+        layout.set_visible("b".to_string(), false);
+
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(
+            json,
+            r#"{"columns":[{"index":"a","visible":true},{"index":"b","visible":false}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<TableColumnLayout<String>>(&json).unwrap(),
+            layout
+        );
+    }
+}
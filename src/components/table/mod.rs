@@ -1,23 +1,46 @@
+mod actions;
+mod bulk;
 mod cell;
 mod column;
 mod composable;
+mod editing;
+mod export;
 mod header;
+mod layout;
 mod model;
+mod placeholder;
 mod props;
 mod render;
+mod reorder;
+mod resize;
+mod virtualization;
 
+pub use actions::*;
+pub use bulk::*;
 pub use cell::*;
 pub use column::*;
 pub use composable::*;
+pub use editing::*;
+pub use export::*;
 pub use header::*;
+pub use layout::*;
 pub use model::*;
+pub use placeholder::*;
 pub use props::*;
 pub use render::*;
+pub use reorder::*;
+pub use resize::*;
+pub use virtualization::*;
 
 use crate::ouia;
-use crate::prelude::{Dropdown, ExtendClasses, Icon, MenuChildVariant, MenuToggleVariant};
+use crate::prelude::{Button, ButtonVariant, ExtendClasses, Icon};
 use crate::utils::{Ouia, OuiaComponentType, OuiaSafe};
-use yew::{prelude::*, virtual_dom::VChild};
+use std::fmt::Debug;
+use std::rc::Rc;
+use yew::{
+    prelude::*,
+    virtual_dom::{Key, VChild},
+};
 
 const OUIA: Ouia = ouia!("Table");
 
@@ -45,6 +68,17 @@ where
     pub borders: bool,
     #[prop_or_default]
     pub header: Option<VChild<TableHeader<C>>>,
+    /// Keep the header visible when scrolling.
+    ///
+    /// This is the same as setting the `sticky` property of the [`TableHeader`].
+    #[prop_or_default]
+    pub sticky_header: bool,
+    /// The order and visibility of the columns.
+    ///
+    /// Applies to the columns of the header, as well as the rendered cells. Also see
+    /// [`TableColumnManagement`].
+    #[prop_or_default]
+    pub column_layout: Option<TableColumnLayout<C>>,
     #[prop_or_default]
     pub full_width_details: bool,
     pub entries: M,
@@ -53,6 +87,14 @@ where
     #[prop_or_default]
     pub grid: Option<TableGridMode>,
 
+    /// Only render the visible rows, plus some additional rows before and after.
+    ///
+    /// This is intended for tables with a large number of rows. The table will be wrapped in
+    /// a scroll container, using a sticky header. When using expandable rows, or rows with
+    /// varying heights, use [`RowHeight::Estimated`].
+    #[prop_or_default]
+    pub virtualization: Option<TableVirtualization>,
+
     #[prop_or_default]
     pub onexpand: OnToggleCallback<C, M>,
 
@@ -62,6 +104,39 @@ where
     #[prop_or_default]
     pub row_selected: Option<Callback<<M as TableModel<C>>::Item, bool>>,
 
+    /// The actions of a row.
+    ///
+    /// Rendered in addition to the [`TableEntryRenderer::actions`]. Also see [`RowAction`].
+    #[prop_or_default]
+    pub row_actions:
+        Option<RowActionsCallback<<M as TableModel<C>>::Item, <M as TableModel<C>>::Key>>,
+
+    /// Render a selection checkbox per row, plus a "select all" checkbox in the header.
+    ///
+    /// Also see [`use_table_selection`].
+    #[prop_or_default]
+    pub selection: Option<UseTableSelection<<M as TableModel<C>>::Key>>,
+
+    /// Content to show instead of the entries, e.g. while loading.
+    ///
+    /// Also see [`use_table_remote`].
+    #[prop_or_default]
+    pub placeholder: Option<TablePlaceholder>,
+
+    /// Allow editing rows inline, one at a time.
+    ///
+    /// Also see [`use_table_editing`].
+    #[prop_or_default]
+    pub editing: Option<TableEditing<C, M>>,
+
+    /// Allow reordering the rows, using drag and drop, or the keyboard.
+    ///
+    /// Renders a drag handle per row. Called with the key of the moved row, and its new index.
+    /// The index is relative to the rendered [`Self::entries`], which is the current page when
+    /// paginating. Also see [`use_table_reorder`] and [`reorder_items`].
+    #[prop_or_default]
+    pub onreorder: Option<Callback<(<M as TableModel<C>>::Key, usize)>>,
+
     /// OUIA Component id
     #[prop_or_default]
     pub ouia_id: Option<String>,
@@ -178,10 +253,18 @@ where
 pub fn table<C, M>(props: &TableProperties<C, M>) -> Html
 where
     C: Clone + Eq + 'static,
-    M: PartialEq + TableModel<C> + 'static,
+    M: PartialEq + Clone + TableModel<C> + 'static,
 {
+    let header = use_memo(
+        (props.header.clone(), props.column_layout.clone()),
+        |(header, layout)| match (header, layout) {
+            (Some(header), Some(layout)) => Some(layout.apply_header(header)),
+            (header, _) => header.clone(),
+        },
+    );
+
     let expandable_columns = use_memo(
-        (props.header.clone(), props.mode.is_expandable()),
+        ((*header).clone(), props.mode.is_expandable()),
         |(header, expandable)| {
             if !expandable {
                 return vec![];
@@ -200,40 +283,216 @@ where
     );
 
     let expandable = props.is_expandable() && !props.are_columns_expandable();
+
+    // the number of leading control cells: expand toggle, drag handle, and selection checkbox
+    let controls = [
+        expandable,
+        props.onreorder.is_some(),
+        props.selection.is_some(),
+    ]
+    .into_iter()
+    .filter(|control| *control)
+    .count();
+
+    let sticky = use_memo(((*header).clone(), controls), |(header, controls)| {
+        header.as_ref().map_or(vec![], |header| {
+            let columns = header.props.children.iter().collect::<Vec<_>>();
+            sticky_columns(*controls, columns.iter().map(|c| c.props.as_ref()))
+        })
+    });
+
+    let virtualization =
+        use_table_virtualization(props.entries.len(), props.virtualization.clone());
+
+    let reorder = use_table_reorder(
+        props.entries.len(),
+        props.onreorder.clone().unwrap_or_default(),
+    );
+
+    let context = use_memo(
+        (
+            (*header).clone(),
+            props.mode,
+            props.full_width_details,
+            props.onexpand.0.clone(),
+            props.selection.clone(),
+            props.row_actions.clone(),
+            props.editing.clone(),
+            props.onreorder.is_some().then_some(reorder),
+            expandable_columns,
+            sticky.clone(),
+        ),
+        |(
+            header,
+            mode,
+            full_width_details,
+            onexpand,
+            selection,
+            row_actions,
+            editing,
+            reorder,
+            expandable_columns,
+            sticky,
+        )| RowContext {
+            header: header.clone(),
+            mode: *mode,
+            full_width_details: *full_width_details,
+            onexpand: onexpand.clone(),
+            selection: selection.clone(),
+            row_actions: row_actions.clone(),
+            editing: editing.clone(),
+            reorder: reorder.clone(),
+            expandable_columns: expandable_columns.clone(),
+            sticky: sticky.clone(),
+        },
+    );
+
+    // changes whenever the entries do, allowing rows to skip rendering otherwise
+    let revision = use_memo(props.entries.clone(), |_| ());
+
     html!(
         <ComposableTable
             id={&props.id}
             class={props.class.clone()}
-            sticky_header={props.header.as_ref().is_some_and(|header| header.props.sticky)}
+            sticky_header={props.sticky_header || props.header.as_ref().is_some_and(|header| header.props.sticky)}
+            scrollable={sticky.iter().any(Option::is_some)}
             mode={props.mode}
             borders={props.borders}
             grid={props.grid}
             ouia_id={props.ouia_id.clone()}
             ouia_type={props.ouia_type}
             ouia_safe={props.ouia_safe}
+            virtualization={virtualization.clone()}
         >
             if let Some(caption) = &props.caption {
                 <Caption>{caption}</Caption>
             }
-            if let Some(header) = props.header.clone() {
-                <TableHeader<C> {expandable} ..(*header.props).clone() />
+            if let Some(header) = (*header).clone() {
+                <TableHeader<C>
+                    {expandable}
+                    editable={props.editing.is_some()}
+                    draggable={props.onreorder.is_some()}
+                    select_all={props.selection.as_ref().map(|selection| selection.header_params())}
+                    ..(*header.props).clone()
+                />
+            }
+            if let Some(placeholder) = &props.placeholder {
+                { placeholder.render(column_count(&context, expandable)) }
+            } else {
+                { render_entries(props, &context, &revision, &virtualization) }
             }
-            { render_entries(props, &expandable_columns) }
         </ComposableTable>
     )
 }
 
-fn render_entries<C, M>(props: &TableProperties<C, M>, expandable_columns: &[C]) -> Html
+/// The number of cells of the header row.
+fn column_count<C, T, K>(context: &RowContext<C, T, K>, expandable: bool) -> usize
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    let Some(header) = &context.header else {
+        return 1;
+    };
+
+    [
+        expandable,
+        context.selection.is_some(),
+        context.editing.is_some(),
+        context.reorder.is_some(),
+        !header.props.hide_actions,
+    ]
+    .into_iter()
+    .filter(|extra| *extra)
+    .count()
+        + header.props.children.len()
+}
+
+/// Everything required to render a row, independent of the table properties.
+struct RowContext<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    header: Option<VChild<TableHeader<C>>>,
+    mode: TableMode,
+    full_width_details: bool,
+    onexpand: ExpansionCallback<K, C>,
+    selection: Option<UseTableSelection<K>>,
+    row_actions: Option<RowActionsCallback<T, K>>,
+    editing: Option<UseTableEditing<C, T, K>>,
+    reorder: Option<UseTableReorder<K>>,
+    expandable_columns: Rc<Vec<C>>,
+    /// The sticky state of the columns of the header
+    sticky: Rc<Vec<Option<(StickyColumn, bool)>>>,
+}
+
+impl<C, T, K> RowContext<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: Clone + 'static,
+    K: Clone + Eq + 'static,
+{
+    /// Check if rows start with an expand toggle.
+    fn has_toggle(&self) -> bool {
+        self.mode.is_expandable() && self.expandable_columns.is_empty()
+    }
+
+    /// The sticky state of the leading control cell at position `n`.
+    fn sticky_control(&self, n: usize) -> Option<StickyColumn> {
+        column::sticky_control(n, &self.sticky)
+    }
+}
+
+fn render_entries<C, M>(
+    props: &TableProperties<C, M>,
+    context: &Rc<RowContext<C, M::Item, M::Key>>,
+    revision: &Rc<()>,
+    virtualization: &UseTableVirtualization,
+) -> Html
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
+    let window = &virtualization.window;
+    let entries: Box<dyn Iterator<Item = _>> = match &context.reorder {
+        Some(reorder) if reorder.is_dragging() => {
+            Box::new(reorder.preview(props.entries.iter().collect()).into_iter())
+        }
+        _ => Box::new(props.entries.iter()),
+    };
+    let entries = entries
+        .enumerate()
+        .skip(window.start)
+        .take(window.len())
+        .map(|(index, entry)| {
+            let drag = context
+                .reorder
+                .as_ref()
+                .map(|reorder| reorder.row_params(entry.key.clone(), index));
+            (entry, drag)
+        });
+
     if props.is_expandable() {
-        props
-            .entries
-            .iter()
-            .map(|entry| render_expandable_entry(props, entry, expandable_columns))
-            .collect()
+        let subscribers = props.entries.expansion_subscribers();
+        html!(<>
+            { virtualization.before_body() }
+            { for entries.map(|(entry, drag)| html!(
+                <ExpandableEntry<C, M::Item, M::Key>
+                    key={entry.key.clone()}
+                    item={entry.value.clone()}
+                    entry_key={entry.key.clone()}
+                    expansion={entry.expansion}
+                    subscribers={subscribers.clone()}
+                    context={context.clone()}
+                    revision={revision.clone()}
+                    {drag}
+                />
+            )) }
+            { virtualization.after_body() }
+        </>)
     } else {
         let row_click_cb = {
             let onrowclick = props.onrowclick.clone();
@@ -244,11 +503,13 @@ where
             })
         };
         html!(
-            <TableBody> {
-                for props.entries.iter().map(|entry| {
+            <TableBody>
+                { virtualization.before_row() }
+                { for entries.map(|(entry, drag)| {
                     let selected = props.row_selected.as_ref().is_some_and(|f| f.emit(entry.value.clone()));
-                    let content = { render_row(props, &entry, |_| false)};
-                    let onclick = if props.onrowclick.is_some() {
+                    let content = { render_row(context, entry.value, &entry.key, &entry.expansion, drag.clone(), |_| false)};
+                    let editing = context.editing.as_ref().is_some_and(|editing| editing.is_editing(&entry.key));
+                    let onclick = if props.onrowclick.is_some() && !editing {
                         let cb = row_click_cb.clone();
                         let val: M::Item = entry.value.clone();
                         Some(Callback::from(move |_| cb.emit(val.clone())))
@@ -256,51 +517,144 @@ where
                         None
                     };
                     html! {
-                        <TableRow {onclick} {selected}>
+                        <TableRow key={entry.key.clone()} class={edit_row_class(editing)} {onclick} {selected} {drag}>
                             {content}
                         </TableRow>
                     }
-                })
-            } </TableBody>
+                }) }
+                { virtualization.after_row() }
+            </TableBody>
         )
     }
 }
 
-fn render_expandable_entry<C, M>(
-    props: &TableProperties<C, M>,
-    entry: TableModelEntry<M::Item, M::Key, C>,
-    expandable_columns: &[C],
+#[derive(Properties)]
+struct ExpandableEntryProperties<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
+{
+    item: T,
+    entry_key: K,
+    expansion: Option<ExpansionState<C>>,
+    subscribers: Option<ExpansionSubscribers<K, C>>,
+    context: Rc<RowContext<C, T, K>>,
+    /// The revision of the entries, as items can't be compared themselves
+    revision: Rc<()>,
+    drag: Option<DragParams>,
+}
+
+impl<C, T, K> PartialEq for ExpandableEntryProperties<C, T, K>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.entry_key == other.entry_key
+            && self.expansion == other.expansion
+            && self.subscribers == other.subscribers
+            && Rc::ptr_eq(&self.context, &other.context)
+            && Rc::ptr_eq(&self.revision, &other.revision)
+            && self.drag == other.drag
+    }
+}
+
+/// An expandable entry, tracking its own expansion state.
+///
+/// When the model provides subscriptions, toggling the expansion state of an entry only
+/// re-renders this entry.
+#[function_component(ExpandableEntry)]
+fn expandable_entry<C, T, K>(props: &ExpandableEntryProperties<C, T, K>) -> Html
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
+{
+    let expansion = use_state_eq(|| props.expansion.clone());
+
+    // follow the state provided by the table
+    {
+        let expansion = expansion.clone();
+        use_effect_with(props.expansion.clone(), move |state| {
+            expansion.set(state.clone());
+        });
+    }
+
+    // subscribe to changes of our own state
+    {
+        let expansion = expansion.clone();
+        use_effect_with(
+            (props.subscribers.clone(), props.entry_key.clone()),
+            move |(subscribers, key)| {
+                let subscription = subscribers.as_ref().map(|subscribers| {
+                    subscribers.subscribe(
+                        key.clone(),
+                        Callback::from(move |state| expansion.set(state)),
+                    )
+                });
+                move || drop(subscription)
+            },
+        );
+    }
+
+    render_expandable_entry(
+        &props.context,
+        &props.item,
+        &props.entry_key,
+        &expansion,
+        props.drag.clone(),
+    )
+}
+
+fn render_expandable_entry<C, T, K>(
+    context: &RowContext<C, T, K>,
+    item: &T,
+    key: &K,
+    expansion: &Option<ExpansionState<C>>,
+    drag: Option<DragParams>,
 ) -> Html
 where
     C: Clone + Eq + 'static,
-    M: PartialEq + TableModel<C> + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
 {
-    let expansion = entry.expansion.clone();
     let expanded = expansion.is_some();
+    let expandable_columns = &context.expandable_columns;
 
-    let key = entry.key.clone();
-
-    let mut cols = props
+    let mut cols = context
         .header
         .as_ref()
         .map_or(0, |header| header.props.children.len())
         + 1;
 
+    if context.selection.is_some() {
+        cols += 1;
+    }
+
+    if context.editing.is_some() {
+        cols += 1;
+    }
+
+    if context.reorder.is_some() {
+        cols += 1;
+    }
+
     let mut cells: Vec<Html> = Vec::with_capacity(cols);
 
     if expandable_columns.is_empty()
-        && !entry
-            .value
+        && !item
             .is_full_width_details()
-            .unwrap_or(props.full_width_details)
+            .unwrap_or(context.full_width_details)
     {
         cells.push(html! {<TableData />});
         cols -= 1;
     }
 
     let details = match expansion {
-        Some(ExpansionState::Row) => entry.value.render_details(),
-        Some(ExpansionState::Column(col)) => entry.value.render_column_details(&col),
+        Some(ExpansionState::Row) => item.render_details(),
+        Some(ExpansionState::Column(col)) => item.render_column_details(col),
         None => vec![],
     };
 
@@ -329,25 +683,28 @@ where
 
     let onclick = {
         let key = key.clone();
-        props
+        context
             .onexpand
-            .0
             .reform(move |_| (key.clone(), ExpansionState::Row))
     };
 
     html!(
-        <TableBody {key} {expanded}>
-            <TableRow control_row={!expandable_columns.is_empty() && props.mode.is_expandable()}>
+        <TableBody {expanded}>
+            <TableRow
+                class={edit_row_class(context.editing.as_ref().is_some_and(|editing| editing.is_editing(key)))}
+                control_row={!expandable_columns.is_empty() && context.mode.is_expandable()}
+                drag={drag.clone()}
+            >
                 // first column, the toggle
                 if expandable_columns.is_empty() {
-                    <TableData expandable={ExpandParams {
+                    <TableData sticky={context.sticky_control(0)} expandable={ExpandParams {
                         r#type: ExpandType::Row,
                         expanded,
                         ontoggle: onclick,
                     }} />
                 }
                 // then, the actual content
-                { render_row(props, &entry, |column| expandable_columns.contains(column)) }
+                { render_row(context, item, key, expansion, drag, |column| expandable_columns.contains(column)) }
             </TableRow>
 
             // the expanded row details
@@ -358,45 +715,88 @@ where
     )
 }
 
-fn render_row<C, M, F>(
-    props: &TableProperties<C, M>,
-    entry: &TableModelEntry<'_, M::Item, M::Key, C>,
+fn edit_row_class(editing: bool) -> Classes {
+    match editing {
+        true => classes!("pf-v5-c-inline-edit", "pf-m-inline-editable"),
+        false => classes!(),
+    }
+}
+
+fn render_row<C, T, K, F>(
+    context: &RowContext<C, T, K>,
+    item: &T,
+    key: &K,
+    expansion: &Option<ExpansionState<C>>,
+    drag: Option<DragParams>,
     expandable: F,
 ) -> Html
 where
     C: Clone + Eq + 'static,
-    M: PartialEq + TableModel<C> + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
     F: Fn(&C) -> bool,
 {
-    let actions = entry.value.actions();
+    let actions = context
+        .row_actions
+        .as_ref()
+        .map(|row_actions| row_actions.emit(item.clone()))
+        .unwrap_or_default();
 
-    let cols = props
+    let cols = context
         .header
         .iter()
         .flat_map(|header| header.props.children.iter());
 
+    let select = context
+        .selection
+        .as_ref()
+        .map(|selection| selection.row_params(key.clone()));
+
+    let editing = context
+        .editing
+        .as_ref()
+        .filter(|editing| editing.is_editing(key));
+
+    // the leading control cells, following the expand toggle
+    let drag_position = context.has_toggle() as usize;
+    let select_position = drag_position + drag.is_some() as usize;
+
     html!(<>
-        { for cols.map(|column| {
+        if let Some(drag) = drag {
+            <TableData {drag} sticky={context.sticky_control(drag_position)} />
+        }
+
+        if let Some(select) = select {
+            <TableData {select} sticky={context.sticky_control(select_position)} />
+        }
+
+        { for cols.zip(context.sticky.iter()).map(|(column, sticky)| {
 
             let index = column.props.index.clone();
             let expandable = expandable(&index);
 
-            // main cell content
-            let cell = entry.value.render_cell(CellContext {
-                column: &column.props.index,
-            });
+            let (sticky, sticky_border) = sticky
+                .clone()
+                .map_or((None, false), |(sticky, border)| (Some(sticky), border));
 
-            let key = entry.key.clone();
+            // main cell content, or the edit widget when editing
+            let cell = editing
+                .and_then(|editing| editing.render_cell(&column.props.index))
+                .unwrap_or_else(|| item.render_cell(CellContext {
+                    column: &column.props.index,
+                }));
+
+            let key = key.clone();
             let expandable = expandable.then(|| ExpandParams {
                 r#type: ExpandType::Column,
-                ontoggle: props.onexpand.0.reform({
+                ontoggle: context.onexpand.reform({
                     let index = index.clone();
                     move |_| {
                         let toggle = ExpansionState::Column(index.clone());
                         (key.clone(), toggle)
                     }
                 }),
-                expanded: entry.expansion == Some(ExpansionState::Column(index.clone())),
+                expanded: expansion == &Some(ExpansionState::Column(index.clone())),
             });
 
             html!(
@@ -405,35 +805,101 @@ where
                     {expandable}
                     center={cell.center}
                     text_modifier={cell.text_modifier}
+                    {sticky}
+                    {sticky_border}
                 >
                     { cell.content.clone() }
                 </TableData>
             )
         })}
 
-        <RowActions {actions} />
+        if let Some(editing) = &context.editing {
+            { render_edit_action(context, editing, item, key) }
+        }
+
+        <TableRowActions<K> entry_key={key.clone()} {actions} menu={item.actions()} />
     </>)
 }
 
-#[derive(PartialEq, Properties)]
-struct RowActionsProperties {
-    actions: Vec<MenuChildVariant>,
-}
+/// Render the inline edit action of a row: "edit" or "save" and "cancel".
+fn render_edit_action<C, T, K>(
+    context: &RowContext<C, T, K>,
+    editing: &UseTableEditing<C, T, K>,
+    item: &T,
+    key: &K,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + Clone + 'static,
+    K: Into<Key> + Clone + Debug + Eq + 'static,
+{
+    let columns = context
+        .header
+        .iter()
+        .flat_map(|header| header.props.children.iter())
+        .map(|column| column.props.index.clone())
+        .collect::<Vec<_>>();
+
+    let content = if editing.is_editing(key) {
+        let valid = editing.is_valid(&columns);
+        let onsave = {
+            let editing = editing.clone();
+            Callback::from(move |_| {
+                editing.save(&columns);
+            })
+        };
+        let oncancel = {
+            let editing = editing.clone();
+            Callback::from(move |_| editing.cancel())
+        };
 
-#[function_component(RowActions)]
-fn row_actions(props: &RowActionsProperties) -> Html {
-    html!(<>
-        if !props.actions.is_empty() {
-            <TableData action=true>
-                <Dropdown
-                    variant={MenuToggleVariant::Plain}
-                    icon={Icon::EllipsisV}
-                >
-                    { props.actions.clone() }
-                </Dropdown>
-            </TableData>
-        }
-    </>)
+        html!(<>
+            <div class="pf-v5-c-inline-edit__action pf-m-valid">
+                <Button
+                    variant={ButtonVariant::Plain}
+                    icon={Icon::Check}
+                    aria_label="Save"
+                    disabled={!valid}
+                    onclick={onsave}
+                />
+            </div>
+            <div class="pf-v5-c-inline-edit__action">
+                <Button
+                    variant={ButtonVariant::Plain}
+                    icon={Icon::Times}
+                    aria_label="Cancel"
+                    onclick={oncancel}
+                />
+            </div>
+        </>)
+    } else {
+        let onedit = {
+            let editing = editing.clone();
+            let key = key.clone();
+            let item = item.clone();
+            Callback::from(move |evt: MouseEvent| {
+                evt.stop_propagation();
+                editing.edit(key.clone(), item.clone());
+            })
+        };
+
+        html!(
+            <div class="pf-v5-c-inline-edit__action pf-m-enable-editable">
+                <Button
+                    variant={ButtonVariant::Plain}
+                    icon={Icon::PencilAlt}
+                    aria_label="Edit"
+                    onclick={onedit}
+                />
+            </div>
+        )
+    };
+
+    html!(
+        <TableData class="pf-v5-c-table__inline-edit-action">
+            { content }
+        </TableData>
+    )
 }
 
 #[derive(Clone, Debug, PartialEq)]
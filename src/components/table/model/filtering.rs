@@ -0,0 +1,917 @@
+use super::TableDataModel;
+use crate::prelude::{
+    Button, ButtonVariant, Chip, ChipGroup, ToolbarContent, ToolbarGroup, ToolbarItem,
+    ToolbarItemType,
+};
+use chrono::NaiveDate;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use yew::{prelude::*, virtual_dom::VChild};
+
+/// The value of a column filter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableFilterValue {
+    /// Match items containing the text (case-insensitive).
+    Text(String),
+    /// Match items having one of the options.
+    Options(Vec<String>),
+    /// Match items within the (inclusive) numeric range.
+    Range { from: Option<f64>, to: Option<f64> },
+    /// Match items within the (inclusive) date range.
+    DateRange {
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    },
+}
+
+impl TableFilterValue {
+    /// Check if the value doesn't filter anything.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.is_empty(),
+            Self::Options(options) => options.is_empty(),
+            Self::Range { from, to } => from.is_none() && to.is_none(),
+            Self::DateRange { from, to } => from.is_none() && to.is_none(),
+        }
+    }
+
+    /// The labels of the chips representing this value.
+    pub fn chips(&self) -> Vec<String> {
+        fn range<T: ToString>(from: &Option<T>, to: &Option<T>) -> Vec<String> {
+            match (from, to) {
+                (Some(from), Some(to)) => {
+                    vec![format!("{} – {}", from.to_string(), to.to_string())]
+                }
+                (Some(from), None) => vec![format!("≥ {}", from.to_string())],
+                (None, Some(to)) => vec![format!("≤ {}", to.to_string())],
+                (None, None) => vec![],
+            }
+        }
+
+        match self {
+            Self::Text(text) if text.is_empty() => vec![],
+            Self::Text(text) => vec![text.clone()],
+            Self::Options(options) => options.clone(),
+            Self::Range { from, to } => range(from, to),
+            Self::DateRange { from, to } => range(from, to),
+        }
+    }
+}
+
+fn in_range<T: PartialOrd>(value: &T, from: &Option<T>, to: &Option<T>) -> bool {
+    from.as_ref().map_or(true, |from| value >= from) && to.as_ref().map_or(true, |to| value <= to)
+}
+
+/// A function extracting the filterable value from an item.
+type Extractor<T, V> = Rc<dyn Fn(&T) -> V>;
+
+enum TableFilterKind<T> {
+    Text(Extractor<T, String>),
+    Options(Extractor<T, String>),
+    Number(Extractor<T, f64>),
+    Date(Extractor<T, NaiveDate>),
+}
+
+impl<T> Clone for TableFilterKind<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Text(f) => Self::Text(f.clone()),
+            Self::Options(f) => Self::Options(f.clone()),
+            Self::Number(f) => Self::Number(f.clone()),
+            Self::Date(f) => Self::Date(f.clone()),
+        }
+    }
+}
+
+impl<T> TableFilterKind<T> {
+    /// Match an item. Values not matching the kind of filter are ignored.
+    fn matches(&self, value: &TableFilterValue, item: &T) -> bool {
+        match (self, value) {
+            (Self::Text(f) | Self::Options(f), TableFilterValue::Text(text)) => {
+                f(item).to_lowercase().contains(&text.to_lowercase())
+            }
+            (Self::Text(f) | Self::Options(f), TableFilterValue::Options(options)) => {
+                let value = f(item);
+                options.iter().any(|o| o == &value)
+            }
+            (Self::Number(f), TableFilterValue::Range { from, to }) => in_range(&f(item), from, to),
+            (Self::Date(f), TableFilterValue::DateRange { from, to }) => {
+                in_range(&f(item), from, to)
+            }
+            _ => true,
+        }
+    }
+
+    /// Check if a value can be used with this kind of filter.
+    fn accepts(&self, value: &TableFilterValue) -> bool {
+        matches!(
+            (self, value),
+            (
+                Self::Text(_) | Self::Options(_),
+                TableFilterValue::Text(_) | TableFilterValue::Options(_)
+            ) | (Self::Number(_), TableFilterValue::Range { .. })
+                | (Self::Date(_), TableFilterValue::DateRange { .. })
+        )
+    }
+}
+
+struct TableFilter<T> {
+    label: AttrValue,
+    kind: TableFilterKind<T>,
+}
+
+impl<T> Clone for TableFilter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            kind: self.kind.clone(),
+        }
+    }
+}
+
+/// A set of filters, one per filterable column.
+pub struct TableFilters<C, T> {
+    filters: Vec<(C, TableFilter<T>)>,
+}
+
+impl<C, T> Default for TableFilters<C, T> {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+        }
+    }
+}
+
+impl<C, T> Clone for TableFilters<C, T>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            filters: self.filters.clone(),
+        }
+    }
+}
+
+impl<C, T> TableFilters<C, T>
+where
+    C: Clone + Eq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(mut self, column: C, label: impl Into<AttrValue>, kind: TableFilterKind<T>) -> Self {
+        self.filters.retain(|(c, _)| c != &column);
+        self.filters.push((
+            column,
+            TableFilter {
+                label: label.into(),
+                kind,
+            },
+        ));
+        self
+    }
+
+    /// Add a filter matching text contained in the column.
+    ///
+    /// Adding a filter for a column a second time replaces the previous one.
+    pub fn text<F>(self, column: C, label: impl Into<AttrValue>, f: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.with(column, label, TableFilterKind::Text(Rc::new(f)))
+    }
+
+    /// Add a filter selecting one or more options (e.g. the variants of an enum) of the column.
+    pub fn options<F>(self, column: C, label: impl Into<AttrValue>, f: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.with(column, label, TableFilterKind::Options(Rc::new(f)))
+    }
+
+    /// Add a filter matching a numeric range of the column.
+    pub fn number_range<F>(self, column: C, label: impl Into<AttrValue>, f: F) -> Self
+    where
+        F: Fn(&T) -> f64 + 'static,
+    {
+        self.with(column, label, TableFilterKind::Number(Rc::new(f)))
+    }
+
+    /// Add a filter matching a date range of the column.
+    pub fn date_range<F>(self, column: C, label: impl Into<AttrValue>, f: F) -> Self
+    where
+        F: Fn(&T) -> NaiveDate + 'static,
+    {
+        self.with(column, label, TableFilterKind::Date(Rc::new(f)))
+    }
+
+    fn get(&self, column: &C) -> Option<&TableFilter<T>> {
+        self.filters
+            .iter()
+            .find_map(|(c, f)| (c == column).then_some(f))
+    }
+
+    /// Get the label of the filter of a column.
+    pub fn label(&self, column: &C) -> Option<AttrValue> {
+        self.get(column).map(|f| f.label.clone())
+    }
+
+    /// Check if an item matches all active filters.
+    ///
+    /// Columns without a filter are ignored.
+    pub fn matches(&self, criteria: &TableFilterCriteria<C>, item: &T) -> bool {
+        criteria.iter().all(|(column, value)| {
+            self.get(column)
+                .map_or(true, |filter| filter.kind.matches(value, item))
+        })
+    }
+
+    /// Evaluate the items matching the filter criteria.
+    ///
+    /// The result is a list of indices into the provided items, in their original order.
+    pub fn filter_indices<'a>(
+        &self,
+        criteria: &TableFilterCriteria<C>,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> Vec<usize>
+    where
+        T: 'a,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, item)| self.matches(criteria, item).then_some(index))
+            .collect()
+    }
+}
+
+/// The active filters of a table, in the order they got added.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableFilterCriteria<C>(Vec<(C, TableFilterValue)>)
+where
+    C: Clone + Eq;
+
+impl<C> Default for TableFilterCriteria<C>
+where
+    C: Clone + Eq,
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<C> TableFilterCriteria<C>
+where
+    C: Clone + Eq,
+{
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(C, TableFilterValue)> {
+        self.0.iter()
+    }
+
+    /// Get the filter value of a column.
+    pub fn get(&self, column: &C) -> Option<&TableFilterValue> {
+        self.0.iter().find_map(|(c, v)| (c == column).then_some(v))
+    }
+
+    /// Set the filter value of a column. Setting an empty value removes the filter.
+    pub fn set(&mut self, column: C, value: TableFilterValue) {
+        if value.is_empty() {
+            self.remove(&column);
+            return;
+        }
+
+        match self.0.iter_mut().find(|(c, _)| c == &column) {
+            Some((_, current)) => *current = value,
+            None => self.0.push((column, value)),
+        }
+    }
+
+    /// Remove the filter of a column.
+    pub fn remove(&mut self, column: &C) {
+        self.0.retain(|(c, _)| c != column);
+    }
+
+    /// Remove a single chip of a column's filter.
+    ///
+    /// For option filters, this only removes the option. Otherwise, the whole filter is removed.
+    pub fn remove_chip(&mut self, column: &C, chip: &str) {
+        match self.get(column) {
+            Some(TableFilterValue::Options(options)) => {
+                let options = options.iter().filter(|o| *o != chip).cloned().collect();
+                self.set(column.clone(), TableFilterValue::Options(options));
+            }
+            _ => self.remove(column),
+        }
+    }
+
+    /// Toggle a single option of a column's option filter.
+    pub fn toggle_option(&mut self, column: C, option: impl Into<String>, selected: bool) {
+        let option = option.into();
+        let mut options = match self.get(&column) {
+            Some(TableFilterValue::Options(options)) => options.clone(),
+            _ => vec![],
+        };
+        options.retain(|o| o != &option);
+        if selected {
+            options.push(option);
+        }
+        self.set(column, TableFilterValue::Options(options));
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// A [`TableDataModel`] filtering another one.
+///
+/// The keys of the wrapped model are passed through unchanged. Keys of rows being filtered out
+/// are still considered to be part of the model (see [`TableDataModel::contains`]), so that any
+/// state attached to them (like the selection of [`super::use_table_selection`]) survives a
+/// change of the filters.
+pub struct FilteredTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    _marker: PhantomData<C>,
+    model: M,
+    indices: Rc<Vec<usize>>,
+}
+
+impl<C, M> FilteredTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    pub fn new(
+        model: M,
+        criteria: &TableFilterCriteria<C>,
+        filters: &TableFilters<C, M::Item>,
+    ) -> Self {
+        let indices = filters.filter_indices(criteria, model.iter().map(|(_, item)| item));
+
+        Self {
+            _marker: Default::default(),
+            model,
+            indices: Rc::new(indices),
+        }
+    }
+
+    /// The number of items, before filtering.
+    pub fn total(&self) -> usize {
+        self.model.len()
+    }
+}
+
+impl<C, M> Clone for FilteredTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _marker: Default::default(),
+            model: self.model.clone(),
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<C, M> PartialEq for FilteredTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.indices, &other.indices) && self.model == other.model
+    }
+}
+
+impl<C, M> TableDataModel<C> for FilteredTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + 'static,
+{
+    type Iterator<'i> = std::vec::IntoIter<(Self::Key, &'i Self::Item)>;
+    type Item = M::Item;
+    type Key = M::Key;
+
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    fn contains(&self, key: &Self::Key) -> bool {
+        self.model.contains(key)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        let mut indices = self.indices.iter().copied().peekable();
+        self.model
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| indices.next_if_eq(&index).is_some().then_some(entry))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// The callbacks handed out by a [`UseTableFilter`], by column.
+struct FilterCallbacks<C> {
+    set: Vec<(C, Callback<TableFilterValue>)>,
+    text: Vec<(C, Callback<String>)>,
+    option: Vec<(C, Callback<(String, bool)>)>,
+}
+
+impl<C> Default for FilterCallbacks<C> {
+    fn default() -> Self {
+        Self {
+            set: Vec::new(),
+            text: Vec::new(),
+            option: Vec::new(),
+        }
+    }
+}
+
+/// Applies changes to the latest criteria.
+struct CriteriaUpdater<C>
+where
+    C: Clone + Eq,
+{
+    current: Rc<RefCell<TableFilterCriteria<C>>>,
+    setter: UseStateSetter<TableFilterCriteria<C>>,
+}
+
+impl<C> CriteriaUpdater<C>
+where
+    C: Clone + Eq,
+{
+    fn update(&self, f: impl FnOnce(&mut TableFilterCriteria<C>)) {
+        let mut next = self.current.borrow().clone();
+        f(&mut next);
+        *self.current.borrow_mut() = next.clone();
+        self.setter.set(next);
+    }
+}
+
+fn cached<C, T>(cache: &mut Vec<(C, T)>, column: C, f: impl FnOnce(C) -> T) -> T
+where
+    C: Clone + Eq,
+    T: Clone,
+{
+    if let Some((_, value)) = cache.iter().find(|(c, _)| c == &column) {
+        return value.clone();
+    }
+    let value = f(column.clone());
+    cache.push((column, value.clone()));
+    value
+}
+
+/// The result of [`use_table_filter`].
+pub struct UseTableFilter<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    /// The filtered model
+    pub model: FilteredTableModel<C, M>,
+    /// The currently active filters
+    pub criteria: UseStateHandle<TableFilterCriteria<C>>,
+    filters: Rc<TableFilters<C, M::Item>>,
+    /// The latest criteria, including changes which are not rendered yet
+    current: Rc<RefCell<TableFilterCriteria<C>>>,
+    callbacks: Rc<RefCell<FilterCallbacks<C>>>,
+}
+
+impl<C, M> UseTableFilter<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    /// Get the current text of a text filter, for the `value` of a [`crate::prelude::SearchInput`].
+    pub fn text(&self, column: &C) -> String {
+        match self.criteria.get(column) {
+            Some(TableFilterValue::Text(text)) => text.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// Check if an option of an option filter is selected.
+    pub fn is_selected(&self, column: &C, option: &str) -> bool {
+        matches!(self.criteria.get(column), Some(TableFilterValue::Options(options)) if options.iter().any(|o| o == option))
+    }
+
+    fn updater(&self) -> CriteriaUpdater<C> {
+        CriteriaUpdater {
+            current: self.current.clone(),
+            setter: self.criteria.setter(),
+        }
+    }
+
+    /// Get a callback, setting the value of a column's filter.
+    ///
+    /// Values which don't match the kind of the column's filter (e.g. a text for a
+    /// number range filter) are rejected. The callback is the same for every call with the
+    /// same column.
+    pub fn onset(&self, column: C) -> Callback<TableFilterValue> {
+        cached(&mut self.callbacks.borrow_mut().set, column, |column| {
+            let filters = self.filters.clone();
+            let updater = self.updater();
+            Callback::from(move |value: TableFilterValue| {
+                match filters.get(&column) {
+                    Some(filter) if filter.kind.accepts(&value) => {}
+                    Some(filter) => {
+                        log::warn!(
+                            "Rejecting filter value {value:?} not matching the filter '{}'",
+                            filter.label
+                        );
+                        return;
+                    }
+                    None => {
+                        log::warn!(
+                            "Rejecting filter value {value:?} for a column without a filter"
+                        );
+                        return;
+                    }
+                }
+                updater.update(|criteria| criteria.set(column.clone(), value));
+            })
+        })
+    }
+
+    /// Get a callback, setting the text of a column's filter.
+    ///
+    /// This can be used for the `onchange` property of a [`crate::prelude::SearchInput`].
+    pub fn ontext(&self, column: C) -> Callback<String> {
+        let onset = self.onset(column.clone());
+        cached(&mut self.callbacks.borrow_mut().text, column, |_| {
+            onset.reform(TableFilterValue::Text)
+        })
+    }
+
+    /// Get a callback, toggling an option of a column's filter.
+    pub fn onoption(&self, column: C) -> Callback<(String, bool)> {
+        cached(&mut self.callbacks.borrow_mut().option, column, |column| {
+            let updater = self.updater();
+            Callback::from(move |(option, selected): (String, bool)| {
+                updater.update(|criteria| criteria.toggle_option(column.clone(), option, selected));
+            })
+        })
+    }
+
+    /// Remove all filters.
+    pub fn clear(&self) {
+        self.updater()
+            .update(|criteria| *criteria = Default::default());
+    }
+
+    /// Render the chips of the active filters, as content of a [`crate::prelude::Toolbar`].
+    ///
+    /// Each filtered column gets its own chip group, followed by a "Clear all filters" button.
+    /// If no filter is active, nothing will be rendered.
+    pub fn chips(&self) -> Option<VChild<ToolbarContent>> {
+        if self.criteria.is_empty() {
+            return None;
+        }
+
+        let groups = self.criteria.iter().map(|(column, value)| {
+            let label = self.filters.label(column).map(|label| label.to_string());
+
+            html!(
+                <ToolbarItem r#type={ToolbarItemType::ChipGroup}>
+                    <ChipGroup {label}>
+                        { for value.chips().into_iter().map(|chip| {
+                            let onclose = {
+                                let updater = self.updater();
+                                let column = column.clone();
+                                let chip = chip.clone();
+                                Callback::from(move |()| {
+                                    updater.update(|criteria| criteria.remove_chip(&column, &chip));
+                                })
+                            };
+                            html_nested!(<Chip text={chip} {onclose} />)
+                        }) }
+                    </ChipGroup>
+                </ToolbarItem>
+            )
+        });
+
+        let onclear = {
+            let updater = self.updater();
+            Callback::from(move |_| updater.update(|criteria| *criteria = Default::default()))
+        };
+
+        Some(html_nested!(
+            <ToolbarContent chip_container=true>
+                <ToolbarGroup>
+                    { for groups }
+                </ToolbarGroup>
+                <ToolbarItem>
+                    <Button variant={ButtonVariant::Link} onclick={onclear}>
+                        { "Clear all filters" }
+                    </Button>
+                </ToolbarItem>
+            </ToolbarContent>
+        ))
+    }
+}
+
+impl<C, M> Clone for UseTableFilter<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            criteria: self.criteria.clone(),
+            filters: self.filters.clone(),
+            current: self.current.clone(),
+            callbacks: self.callbacks.clone(),
+        }
+    }
+}
+
+impl<C, M> PartialEq for UseTableFilter<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.model == other.model
+            && self.criteria == other.criteria
+            && Rc::ptr_eq(&self.filters, &other.filters)
+    }
+}
+
+/// Filter the data of a table, on the client side.
+///
+/// This hook takes a [`TableDataModel`] and a set of filters, and returns a filtered model,
+/// which can then be handed over to [`super::use_table_data`] (or [`super::use_table_sorting`]).
+/// An item needs to match the filters of all columns. Option filters match if the item has any
+/// of the selected options.
+///
+/// The filters will be initialized once, using the initializer function. The active filters can
+/// be shown using [`UseTableFilter::chips`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name, Size };
+/// #[derive(Clone)]
+/// struct ExampleEntry { name: String, size: usize };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///       Column::Size => html!({self.size}).into(),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let entries = use_memo((), |()| {
+///       vec![
+///           ExampleEntry { name: "foo".into(), size: 3 },
+///           ExampleEntry { name: "bar".into(), size: 5 },
+///       ]
+///   });
+///
+///   let filter = use_table_filter(MemoizedTableModel::new(entries), || {
+///       TableFilters::new()
+///           .text(Column::Name, "Name", |entry: &ExampleEntry| entry.name.clone())
+///           .number_range(Column::Size, "Size", |entry: &ExampleEntry| entry.size as f64)
+///   });
+///   let (entries, onexpand) = use_table_data(filter.model.clone());
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name} />
+///       <TableColumn<Column> label="Size" index={Column::Size} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <>
+///       <Toolbar>
+///         <ToolbarContent>
+///           <ToolbarItem r#type={ToolbarItemType::SearchFilter}>
+///             <SearchInput
+///               value={filter.text(&Column::Name)}
+///               onchange={filter.ontext(Column::Name)}
+///             />
+///           </ToolbarItem>
+///         </ToolbarContent>
+///         { for filter.chips() }
+///       </Toolbar>
+///       <Table<Column, UseTableData<Column, FilteredTableModel<Column, MemoizedTableModel<ExampleEntry>>>>
+///         {header}
+///         {entries}
+///         {onexpand}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_filter<C, M, F>(data: M, init: F) -> UseTableFilter<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + Clone + TableDataModel<C> + 'static,
+    F: FnOnce() -> TableFilters<C, M::Item>,
+{
+    let filters = use_memo((), |()| init());
+    let criteria = use_state_eq(TableFilterCriteria::<C>::default);
+    let current = use_mut_ref(TableFilterCriteria::<C>::default);
+    *current.borrow_mut() = (*criteria).clone();
+    let callbacks = use_mut_ref(FilterCallbacks::default);
+
+    let model = {
+        let filters = filters.clone();
+        use_memo((data, (*criteria).clone()), move |(data, criteria)| {
+            FilteredTableModel::new(data.clone(), criteria, &filters)
+        })
+    };
+
+    UseTableFilter {
+        model: (*model).clone(),
+        criteria,
+        filters,
+        current,
+        callbacks,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Column {
+        Name,
+        State,
+        Size,
+        Date,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Item(&'static str, &'static str, usize, NaiveDate);
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 1, day).unwrap()
+    }
+
+    fn filters() -> TableFilters<Column, Item> {
+        TableFilters::new()
+            .text(Column::Name, "Name", |item: &Item| item.0.to_string())
+            .options(Column::State, "State", |item: &Item| item.1.to_string())
+            .number_range(Column::Size, "Size", |item: &Item| item.2 as f64)
+            .date_range(Column::Date, "Date", |item: &Item| item.3)
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item("Foo", "running", 1, date(1)),
+            Item("Bar", "stopped", 2, date(2)),
+            Item("foobar", "failed", 3, date(3)),
+            Item("Baz", "running", 4, date(4)),
+        ]
+    }
+
+    fn filter(criteria: &TableFilterCriteria<Column>) -> Vec<&'static str> {
+        let items = items();
+        filters()
+            .filter_indices(criteria, &items)
+            .into_iter()
+            .map(|i| items[i].0)
+            .collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            filter(&Default::default()),
+            vec!["Foo", "Bar", "foobar", "Baz"]
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        let mut criteria = TableFilterCriteria::default();
+        criteria.set(Column::Name, TableFilterValue::Text("FOO".into()));
+        assert_eq!(filter(&criteria), vec!["Foo", "foobar"]);
+
+        // setting an empty value removes the filter
+        criteria.set(Column::Name, TableFilterValue::Text("".into()));
+        assert!(criteria.is_empty());
+    }
+
+    #[test]
+    fn test_options() {
+        let mut criteria = TableFilterCriteria::default();
+        criteria.toggle_option(Column::State, "running", true);
+        assert_eq!(filter(&criteria), vec!["Foo", "Baz"]);
+
+        criteria.toggle_option(Column::State, "failed", true);
+        assert_eq!(filter(&criteria), vec!["Foo", "foobar", "Baz"]);
+
+        criteria.remove_chip(&Column::State, "running");
+        assert_eq!(filter(&criteria), vec!["foobar"]);
+
+        criteria.toggle_option(Column::State, "failed", false);
+        assert!(criteria.is_empty());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut criteria = TableFilterCriteria::default();
+        criteria.set(
+            Column::Size,
+            TableFilterValue::Range {
+                from: Some(2.0),
+                to: None,
+            },
+        );
+        assert_eq!(filter(&criteria), vec!["Bar", "foobar", "Baz"]);
+
+        criteria.set(
+            Column::Date,
+            TableFilterValue::DateRange {
+                from: None,
+                to: Some(date(3)),
+            },
+        );
+        assert_eq!(filter(&criteria), vec!["Bar", "foobar"]);
+        assert_eq!(
+            criteria.get(&Column::Date).unwrap().chips(),
+            vec!["≤ 2023-01-03".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_combined() {
+        let mut criteria = TableFilterCriteria::default();
+        criteria.set(Column::Name, TableFilterValue::Text("ba".into()));
+        criteria.toggle_option(Column::State, "running", true);
+        assert_eq!(filter(&criteria), vec!["Baz"]);
+
+        criteria.remove(&Column::State);
+        assert_eq!(filter(&criteria), vec!["Bar", "foobar", "Baz"]);
+
+        criteria.clear();
+        assert_eq!(filter(&criteria).len(), 4);
+    }
+    #[test]
+    fn test_accepts() {
+        let filters = filters();
+        let accepts = |column: &Column, value: TableFilterValue| {
+            filters.get(column).unwrap().kind.accepts(&value)
+        };
+
+        assert!(accepts(&Column::Name, TableFilterValue::Text("foo".into())));
+        assert!(accepts(&Column::State, TableFilterValue::Options(vec![])));
+        assert!(!accepts(
+            &Column::Name,
+            TableFilterValue::Range {
+                from: None,
+                to: None
+            }
+        ));
+        assert!(accepts(
+            &Column::Size,
+            TableFilterValue::Range {
+                from: None,
+                to: None
+            }
+        ));
+        assert!(!accepts(&Column::Size, TableFilterValue::Text("1".into())));
+        assert!(!accepts(
+            &Column::Date,
+            TableFilterValue::Range {
+                from: None,
+                to: None
+            }
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{StateModel, TableDataModel};
+use super::{ExpansionSubscribers, StateModel, TableDataModel};
 use crate::prelude::{StateModelIter, TableModel};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -109,33 +109,37 @@ where
     M::Key: Hash,
 {
     let state = use_mut_ref(HashMap::<M::Key, ExpansionState<C>>::new);
+    let subscribers = use_memo((), |()| ExpansionSubscribers::<M::Key, C>::new());
+
     let model = {
         let state = state.clone();
+        let subscribers = (*subscribers).clone();
         use_memo(data, move |model| {
             state.borrow_mut().retain(|key, _| model.contains(key));
-            StateModel::new(model.clone(), state)
+            StateModel::new(model.clone(), state).with_subscribers(subscribers)
         })
     };
 
-    let trigger = use_force_update();
-
-    // FIXME: allow toggling entries without re-evaluating the whole table: https://github.com/patternfly-yew/patternfly-yew/issues/69
-    let ontoggle = Callback::from(move |(key, expansion_state)| {
-        match state.borrow_mut().entry(key) {
-            Entry::Vacant(entry) => {
-                entry.insert(expansion_state);
-            }
-            Entry::Occupied(mut entry) => {
-                if entry.get() != &expansion_state {
-                    entry.insert(expansion_state);
-                } else {
-                    entry.remove();
+    // only the toggled row gets notified, there's no need to re-render the whole table
+    let ontoggle = use_callback(
+        (state, subscribers),
+        |(key, expansion_state): (M::Key, ExpansionState<C>), (state, subscribers)| {
+            let next = match state.borrow_mut().entry(key.clone()) {
+                Entry::Vacant(entry) => Some(entry.insert(expansion_state).clone()),
+                Entry::Occupied(mut entry) => {
+                    if entry.get() != &expansion_state {
+                        entry.insert(expansion_state.clone());
+                        Some(expansion_state)
+                    } else {
+                        entry.remove();
+                        None
+                    }
                 }
-            }
-        }
+            };
 
-        trigger.force_update();
-    });
+            subscribers.notify(&key, next);
+        },
+    );
 
     ({ UseTableData { model } }, ontoggle)
 }
@@ -170,6 +174,10 @@ where
     fn iter(&self) -> Self::Iterator<'_> {
         self.model.iter()
     }
+
+    fn expansion_subscribers(&self) -> Option<ExpansionSubscribers<Self::Key, C>> {
+        self.model.expansion_subscribers()
+    }
 }
 
 impl<C, M> Clone for UseTableData<C, M>
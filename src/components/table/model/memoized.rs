@@ -20,6 +20,10 @@ impl<T> MemoizedTableModel<T> {
     pub fn new(entries: Rc<Vec<T>>) -> Self {
         Self { entries }
     }
+
+    pub fn entries(&self) -> &Rc<Vec<T>> {
+        &self.entries
+    }
 }
 
 impl<T> From<Rc<Vec<T>>> for MemoizedTableModel<T> {
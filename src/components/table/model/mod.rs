@@ -1,10 +1,18 @@
+mod filtering;
 mod hook;
 mod memoized;
+mod remote;
+mod selection;
+mod sorting;
 mod state;
 mod table;
 
+pub use filtering::*;
 pub use hook::*;
 pub use memoized::*;
+pub use remote::*;
+pub use selection::*;
+pub use sorting::*;
 pub use state::*;
 use std::fmt::Debug;
 pub use table::*;
@@ -34,6 +42,16 @@ where
 
     /// Iterate over all the items
     fn iter(&self) -> Self::Iterator<'_>;
+
+    /// Subscriptions to the expansion state of rows.
+    ///
+    /// If a model provides subscriptions, a table only re-renders the affected row when its
+    /// expansion state changes. Otherwise, the whole table needs to be re-rendered.
+    ///
+    /// Defaults to not providing subscriptions.
+    fn expansion_subscribers(&self) -> Option<ExpansionSubscribers<Self::Key, C>> {
+        None
+    }
 }
 
 impl<C, M> TableModel<C> for Rc<M>
@@ -56,6 +74,10 @@ where
     fn iter(&self) -> Self::Iterator<'_> {
         self.as_ref().iter()
     }
+
+    fn expansion_subscribers(&self) -> Option<ExpansionSubscribers<Self::Key, C>> {
+        self.as_ref().expansion_subscribers()
+    }
 }
 
 pub trait TableDataModel<C>
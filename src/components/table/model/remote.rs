@@ -0,0 +1,347 @@
+use super::{MemoizedTableModel, TableFilterCriteria, TableSortCriteria};
+use crate::prelude::{
+    use_pagination, Navigation, PaginationControl, TableEntryRenderer, TableHeaderSortBy,
+    TableHeaderSortEvent, TablePlaceholder, UsePagination,
+};
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use web_sys::{AbortController, AbortSignal};
+use yew::prelude::*;
+
+/// The parameters for fetching a page of remote table data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableRemoteRequest<C>
+where
+    C: Clone + Eq,
+{
+    pub control: PaginationControl,
+    pub sort: TableSortCriteria<C>,
+    pub filter: TableFilterCriteria<C>,
+}
+
+/// A page of remote table data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableRemotePage<T> {
+    /// The items of the requested page
+    pub items: Vec<T>,
+    /// The total number of items, if known
+    pub total: Option<usize>,
+}
+
+/// The result of fetching a page of remote table data.
+pub type TableRemoteFuture<T, E> = Pin<Box<dyn Future<Output = Result<TableRemotePage<T>, E>>>>;
+
+/// A source of table data, which is paginated, sorted and filtered remotely (e.g. by a server).
+pub trait TableRemoteSource<C>
+where
+    C: Clone + Eq + 'static,
+{
+    type Item: TableEntryRenderer<C> + Clone + 'static;
+    type Error: Display + 'static;
+
+    /// Fetch a page of data.
+    ///
+    /// The signal gets aborted once the result is no longer needed, e.g. because a newer request
+    /// was started. It can be handed over to the `fetch` API to cancel the in-flight request.
+    fn fetch(
+        &self,
+        request: TableRemoteRequest<C>,
+        signal: AbortSignal,
+    ) -> TableRemoteFuture<Self::Item, Self::Error>;
+}
+
+/// The state of loading remote table data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableRemoteState {
+    Loading,
+    Ready,
+    Failed(String),
+}
+
+/// The result of [`use_table_remote`].
+pub struct UseTableRemote<C, T>
+where
+    C: Clone + Eq + 'static,
+{
+    /// The entries of the current page.
+    ///
+    /// While loading, this still contains the entries of the previous page.
+    pub model: MemoizedTableModel<T>,
+    /// The state of loading the current page
+    pub state: TableRemoteState,
+    /// The pagination state, tracking the total reported by the source
+    pub pagination: UsePagination,
+    /// The current sort criteria
+    pub sort: UseStateHandle<TableSortCriteria<C>>,
+    /// The current filters
+    ///
+    /// Prefer [`Self::onfilter`] for changing them, which also navigates back to the first page.
+    pub filter: UseStateHandle<TableFilterCriteria<C>>,
+    /// Callback for the `onsortevent` property of a [`crate::prelude::TableColumn`]
+    pub onsort: Callback<TableHeaderSortEvent<C>>,
+    /// Set the filters, navigating back to the first page
+    pub onfilter: Callback<TableFilterCriteria<C>>,
+    /// Fetch the current page again
+    pub onretry: Callback<()>,
+}
+
+impl<C, T> UseTableRemote<C, T>
+where
+    C: Clone + Eq + 'static,
+{
+    /// Get the sort state of a column, for the `sortby` property of a [`crate::prelude::TableColumn`].
+    pub fn sortby(&self, column: &C) -> Option<TableHeaderSortBy<C>> {
+        self.sort.get(column)
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self.state, TableRemoteState::Loading)
+    }
+
+    /// The placeholder for the `placeholder` property of the [`super::super::Table`].
+    ///
+    /// Shows placeholder rows while loading, an error (with the option to retry) when loading
+    /// failed, and an empty state when there are no results.
+    pub fn placeholder(&self) -> Option<TablePlaceholder> {
+        match &self.state {
+            TableRemoteState::Loading => {
+                Some(TablePlaceholder::loading(self.pagination.control.per_page))
+            }
+            TableRemoteState::Failed(message) => Some(TablePlaceholder::error(
+                message.clone(),
+                Some(self.onretry.clone()),
+            )),
+            TableRemoteState::Ready if self.model.entries().is_empty() => {
+                Some(TablePlaceholder::empty())
+            }
+            TableRemoteState::Ready => None,
+        }
+    }
+}
+
+impl<C, T> Clone for UseTableRemote<C, T>
+where
+    C: Clone + Eq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            state: self.state.clone(),
+            pagination: self.pagination.clone(),
+            sort: self.sort.clone(),
+            filter: self.filter.clone(),
+            onsort: self.onsort.clone(),
+            onfilter: self.onfilter.clone(),
+            onretry: self.onretry.clone(),
+        }
+    }
+}
+
+impl<C, T> PartialEq for UseTableRemote<C, T>
+where
+    C: Clone + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.model == other.model
+            && self.state == other.state
+            && self.pagination == other.pagination
+            && self.sort == other.sort
+            && self.filter == other.filter
+            && self.onsort == other.onsort
+            && self.onfilter == other.onfilter
+            && self.onretry == other.onretry
+    }
+}
+
+/// Drive a table from a [`TableRemoteSource`].
+///
+/// Whenever the pagination, sort criteria, or filters change, the current page is fetched from
+/// the source. Only the result of the latest request is used: stale requests (e.g. when quickly
+/// paging through the data) get aborted, and their results discarded. Changing the sort criteria
+/// or filters navigates back to the first page.
+///
+/// The pagination control will be initialized using the initializer function. The total is
+/// taken from the latest result.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name };
+/// #[derive(Clone)]
+/// struct ExampleEntry { name: String };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///     }
+///   }
+/// }
+///
+/// #[derive(Clone, PartialEq)]
+/// struct ExampleSource;
+///
+/// impl TableRemoteSource<Column> for ExampleSource {
+///   type Item = ExampleEntry;
+///   type Error = String;
+///
+///   fn fetch(
+///     &self,
+///     request: TableRemoteRequest<Column>,
+///     signal: web_sys::AbortSignal,
+///   ) -> TableRemoteFuture<ExampleEntry, String> {
+///     Box::pin(async move {
+///       // request the data from a server, passing on the signal
+///       let items = (request.control.page * request.control.per_page..)
+///         .take(request.control.per_page)
+///         .map(|n| ExampleEntry { name: format!("Item {n}") })
+///         .collect();
+///       Ok(TableRemotePage { items, total: Some(1000) })
+///     })
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let remote = use_table_remote(ExampleSource, Default::default);
+///   let (entries, onexpand) = use_table_data(remote.model.clone());
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name}
+///         sortby={remote.sortby(&Column::Name)} onsortevent={remote.onsort.clone()} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <>
+///       <SimplePagination
+///         pagination={remote.pagination.clone()}
+///         total={remote.pagination.total}
+///       />
+///       <Table<Column, UseTableData<Column, MemoizedTableModel<ExampleEntry>>>
+///         {header}
+///         {entries}
+///         {onexpand}
+///         placeholder={remote.placeholder()}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_remote<C, S, F>(source: S, init: F) -> UseTableRemote<C, S::Item>
+where
+    C: Clone + Eq + 'static,
+    S: TableRemoteSource<C> + PartialEq + Clone + 'static,
+    F: FnOnce() -> PaginationControl,
+{
+    let total = use_state_eq(|| None);
+    let pagination = use_pagination(*total, init);
+    let sort = use_state_eq(TableSortCriteria::<C>::default);
+    let filter = use_state_eq(TableFilterCriteria::<C>::default);
+
+    let items = use_state(|| Rc::new(Vec::new()));
+    let state = use_state_eq(|| TableRemoteState::Loading);
+    // bumped to re-fetch the current page
+    let retry = use_state_eq(|| 0usize);
+    // the criteria of the last request, to detect criteria changed through the state handles
+    let criteria = use_mut_ref(|| None);
+
+    // fetch the current page
+
+    {
+        let request = TableRemoteRequest {
+            control: pagination.control,
+            sort: (*sort).clone(),
+            filter: (*filter).clone(),
+        };
+        let (total, items, state) = (total.clone(), items.clone(), state.clone());
+        let onnavigation = pagination.onnavigation.clone();
+        use_effect_with((source, request, *retry), move |(source, request, _)| {
+            let current = Some((request.sort.clone(), request.filter.clone()));
+            let changed = *criteria.borrow() != current;
+            let first = criteria.borrow().is_none();
+            *criteria.borrow_mut() = current;
+
+            let controller = match changed && !first && request.control.page > 0 {
+                // go back to the first page first, which will fetch again
+                true => {
+                    onnavigation.emit(Navigation::First);
+                    None
+                }
+                false => AbortController::new().ok(),
+            };
+
+            if let Some(controller) = &controller {
+                state.set(TableRemoteState::Loading);
+                let signal = controller.signal();
+                let fetch = source.fetch(request.clone(), signal.clone());
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = fetch.await;
+                    if signal.aborted() {
+                        // a newer request is pending
+                        return;
+                    }
+
+                    match result {
+                        Ok(page) => {
+                            total.set(page.total);
+                            items.set(Rc::new(page.items));
+                            state.set(TableRemoteState::Ready);
+                        }
+                        Err(err) => {
+                            state.set(TableRemoteState::Failed(err.to_string()));
+                        }
+                    }
+                });
+            }
+
+            move || {
+                if let Some(controller) = controller {
+                    controller.abort();
+                }
+            }
+        });
+    }
+
+    // changing the criteria navigates back to the first page, in the same update
+
+    let onsort = use_callback(
+        (sort.clone(), pagination.onnavigation.clone()),
+        |event: TableHeaderSortEvent<C>, (sort, onnavigation)| {
+            let mut next = (**sort).clone();
+            next.apply(event);
+            sort.set(next);
+            onnavigation.emit(Navigation::First);
+        },
+    );
+
+    let onfilter = use_callback(
+        (filter.clone(), pagination.onnavigation.clone()),
+        |next: TableFilterCriteria<C>, (filter, onnavigation)| {
+            filter.set(next);
+            onnavigation.emit(Navigation::First);
+        },
+    );
+
+    let onretry = use_callback(retry.clone(), |(), retry| retry.set(**retry + 1));
+
+    UseTableRemote {
+        model: MemoizedTableModel::new((*items).clone()),
+        state: (*state).clone(),
+        pagination,
+        sort,
+        filter,
+        onsort,
+        onfilter,
+        onretry,
+    }
+}
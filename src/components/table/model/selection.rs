@@ -0,0 +1,629 @@
+use super::TableDataModel;
+use crate::prelude::{CheckboxState, RowSelectEvent, SelectAllParams, SelectParams};
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A set of selected rows, identified by their keys.
+#[derive(Clone)]
+pub struct TableSelection<K> {
+    selected: HashSet<K>,
+    anchor: Option<K>,
+}
+
+impl<K> PartialEq for TableSelection<K>
+where
+    K: Hash + Eq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.selected == other.selected && self.anchor == other.anchor
+    }
+}
+
+impl<K> Eq for TableSelection<K> where K: Hash + Eq {}
+
+impl<K> Default for TableSelection<K>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        Self {
+            selected: Default::default(),
+            anchor: None,
+        }
+    }
+}
+
+impl<K> Debug for TableSelection<K>
+where
+    K: Hash + Eq + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableSelection")
+            .field("selected", &self.selected)
+            .field("anchor", &self.anchor)
+            .finish()
+    }
+}
+
+impl<K> TableSelection<K>
+where
+    K: Clone + Hash + Eq,
+{
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Iterate over the selected keys, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.selected.iter()
+    }
+
+    /// Select or deselect a single row.
+    pub fn set(&mut self, key: K, selected: bool) {
+        match selected {
+            true => self.selected.insert(key.clone()),
+            false => self.selected.remove(&key),
+        };
+        self.anchor = Some(key);
+    }
+
+    /// Select or deselect all rows from the last changed row (the anchor) to the provided one.
+    ///
+    /// The range is evaluated based on the provided order of keys. If there is no anchor, or
+    /// the anchor is not part of the keys, only the provided row will be changed.
+    pub fn set_range(&mut self, key: K, selected: bool, keys: &[K]) {
+        let range = self
+            .anchor
+            .as_ref()
+            .and_then(|anchor| keys.iter().position(|k| k == anchor))
+            .zip(keys.iter().position(|k| k == &key));
+
+        let Some((from, to)) = range else {
+            self.set(key, selected);
+            return;
+        };
+
+        for key in &keys[from.min(to)..=from.max(to)] {
+            match selected {
+                true => self.selected.insert(key.clone()),
+                false => self.selected.remove(key),
+            };
+        }
+
+        // the anchor stays, so that the range can be adjusted
+    }
+
+    /// Select or deselect all provided rows.
+    pub fn set_all<'k>(&mut self, keys: impl IntoIterator<Item = &'k K>, selected: bool)
+    where
+        K: 'k,
+    {
+        for key in keys {
+            match selected {
+                true => self.selected.insert(key.clone()),
+                false => self.selected.remove(key),
+            };
+        }
+        self.anchor = None;
+    }
+
+    /// Deselect all rows.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Only keep selected rows matching the predicate.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: Fn(&K) -> bool,
+    {
+        self.selected.retain(&f);
+        if !self.anchor.as_ref().is_some_and(f) {
+            self.anchor = None;
+        }
+    }
+
+    /// Evaluate the combined state of the provided rows.
+    pub fn state<'k>(&self, keys: impl IntoIterator<Item = &'k K>) -> CheckboxState
+    where
+        K: 'k,
+    {
+        let mut any = false;
+        let mut all = true;
+
+        for key in keys {
+            match self.selected.contains(key) {
+                true => any = true,
+                false => all = false,
+            }
+        }
+
+        match (any, all) {
+            (true, true) => CheckboxState::Checked,
+            (true, false) => CheckboxState::Indeterminate,
+            (false, _) => CheckboxState::Unchecked,
+        }
+    }
+}
+
+/// The rows selected by "select all", also see [`use_table_selection_with`].
+#[derive(Default)]
+pub enum SelectAllRows<K> {
+    /// The rows of the model, which was provided to the hook
+    #[default]
+    Model,
+    /// All keys, e.g. of all pages of a table paginated on the client side
+    Keys(Rc<Vec<K>>),
+    /// Called when all rows should be selected, e.g. by fetching all keys from a server and
+    /// selecting them using [`UseTableSelection::onchange`].
+    Callback(Callback<()>),
+}
+
+impl<K> Clone for SelectAllRows<K> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Model => Self::Model,
+            Self::Keys(keys) => Self::Keys(keys.clone()),
+            Self::Callback(callback) => Self::Callback(callback.clone()),
+        }
+    }
+}
+
+impl<K> PartialEq for SelectAllRows<K> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Model, Self::Model) => true,
+            (Self::Keys(a), Self::Keys(b)) => Rc::ptr_eq(a, b),
+            (Self::Callback(a), Self::Callback(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The result of [`use_table_selection`].
+///
+/// This can be passed to the `selection` property of the [`crate::prelude::Table`].
+pub struct UseTableSelection<K>
+where
+    K: 'static,
+{
+    /// The keys of the current rows, in order
+    keys: Rc<Vec<K>>,
+    /// The rows selected by "select all"
+    all: SelectAllRows<K>,
+    /// The current selection
+    pub selection: Rc<TableSelection<K>>,
+    /// The combined selection state of all current rows
+    pub state: CheckboxState,
+    /// Check if a row is selected
+    pub selected: Callback<K, bool>,
+    /// Request a change of the selection of a row
+    pub onselect: Callback<(K, RowSelectEvent)>,
+    /// Select or deselect all current rows
+    pub onselectpage: Callback<bool>,
+    /// Select or deselect all rows, also see [`SelectAllRows`]
+    pub onselectall: Callback<bool>,
+    /// Replace the current selection
+    pub onchange: Callback<TableSelection<K>>,
+}
+
+impl<K> Clone for UseTableSelection<K> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            all: self.all.clone(),
+            selection: self.selection.clone(),
+            state: self.state,
+            selected: self.selected.clone(),
+            onselect: self.onselect.clone(),
+            onselectpage: self.onselectpage.clone(),
+            onselectall: self.onselectall.clone(),
+            onchange: self.onchange.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for UseTableSelection<K> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.keys, &other.keys)
+            && self.all == other.all
+            && Rc::ptr_eq(&self.selection, &other.selection)
+            && self.state == other.state
+            && self.selected == other.selected
+            && self.onselect == other.onselect
+            && self.onselectpage == other.onselectpage
+            && self.onselectall == other.onselectall
+            && self.onchange == other.onchange
+    }
+}
+
+impl<K> Debug for UseTableSelection<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UseTableSelection")
+            .field("keys", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K> UseTableSelection<K>
+where
+    K: Clone + 'static,
+{
+    /// The keys of the current rows, in order
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// The number of rows selected by "select all", if known.
+    pub fn total(&self) -> Option<usize> {
+        match &self.all {
+            SelectAllRows::Model => Some(self.keys.len()),
+            SelectAllRows::Keys(keys) => Some(keys.len()),
+            SelectAllRows::Callback(_) => None,
+        }
+    }
+
+    /// Check if "select all" selects more than the current rows.
+    pub fn is_paged(&self) -> bool {
+        !matches!(self.all, SelectAllRows::Model)
+    }
+
+    /// Create the parameters for the selection cell of a row.
+    pub fn row_params(&self, key: K) -> SelectParams {
+        SelectParams {
+            selected: self.selected.emit(key.clone()),
+            disabled: false,
+            onselect: self.onselect.reform(move |event| (key.clone(), event)),
+        }
+    }
+
+    /// Create the parameters for the "select all" checkbox of a table header.
+    ///
+    /// This selects the current rows, use a [`crate::prelude::TableBulkSelect`] to select all
+    /// rows of a paged table.
+    pub fn header_params(&self) -> SelectAllParams {
+        SelectAllParams {
+            state: self.state,
+            onselect: self.onselectpage.clone(),
+        }
+    }
+}
+
+impl<K> UseTableSelection<K>
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    /// Get the selected keys, in the order of the rows.
+    ///
+    /// When "select all" uses [`SelectAllRows::Keys`], these are ordered by all keys. With
+    /// [`SelectAllRows::Callback`], the keys of the current rows come first, followed by the
+    /// remaining selected keys in no particular order. Otherwise, selected keys which are not
+    /// part of the current rows are not included.
+    pub fn selected_keys(&self) -> Vec<K> {
+        let ordered = |keys: &[K]| {
+            keys.iter()
+                .filter(|key| self.selection.is_selected(key))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        match &self.all {
+            SelectAllRows::Model => ordered(&self.keys),
+            SelectAllRows::Keys(keys) => ordered(keys),
+            SelectAllRows::Callback(_) => {
+                let mut result = ordered(&self.keys);
+                let page = self.keys.iter().collect::<HashSet<_>>();
+                result.extend(
+                    self.selection
+                        .iter()
+                        .filter(|key| !page.contains(key))
+                        .cloned(),
+                );
+                result
+            }
+        }
+    }
+}
+
+/// Manage the selection of table rows.
+///
+/// Selection is tracked by the key of a row. So it is preserved when the model changes, as long as
+/// the key is still present. Keys which are no longer part of the model will be removed from the
+/// selection.
+///
+/// The "select all" checkbox of the header, as well as the range selection (shift-click) work with
+/// the rows of the provided model. If the table is paginated, provide the model of the current
+/// page, and use [`use_table_selection_with`] to select the rows of all pages.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name };
+/// #[derive(Clone)]
+/// struct ExampleEntry { name: String };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let entries = use_memo((), |()| {
+///       vec![
+///           ExampleEntry { name: "foo".into() },
+///           ExampleEntry { name: "bar".into() },
+///       ]
+///   });
+///
+///   let model = MemoizedTableModel::new(entries);
+///   let selection = use_table_selection(model.clone());
+///   let (entries, onexpand) = use_table_data(model);
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <>
+///       { format!("{} selected", selection.selection.len()) }
+///       <Table<Column, UseTableData<Column, MemoizedTableModel<ExampleEntry>>>
+///         {header}
+///         {entries}
+///         {onexpand}
+///         {selection}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_selection<C, M>(data: M) -> UseTableSelection<M::Key>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + Clone + TableDataModel<C> + 'static,
+    M::Key: Hash,
+{
+    use_table_selection_with(data, SelectAllRows::Model)
+}
+
+/// Manage the selection of the rows of a paged table.
+///
+/// Works like [`use_table_selection`], providing the model of the current page as `data`. "Select
+/// all" selects the rows defined by `all`, instead of the rows of the current page.
+///
+/// Selected rows are dropped when they are no longer part of `all`. With
+/// [`SelectAllRows::Callback`], the selection of rows is kept, as they might be part of a
+/// different page.
+#[hook]
+pub fn use_table_selection_with<C, M>(
+    data: M,
+    all: SelectAllRows<M::Key>,
+) -> UseTableSelection<M::Key>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + Clone + TableDataModel<C> + 'static,
+    M::Key: Hash,
+{
+    let keys = use_memo(data.clone(), |data| {
+        data.iter().map(|(key, _)| key).collect::<Vec<_>>()
+    });
+
+    let state = use_state(|| Rc::new(TableSelection::<M::Key>::default()));
+
+    let onchange = use_callback(state.clone(), |next: TableSelection<M::Key>, state| {
+        if ***state != next {
+            state.set(Rc::new(next));
+        }
+    });
+
+    // drop the selection of rows which are gone
+
+    use_effect_with((data, all.clone(), onchange.clone()), {
+        let selection = (*state).clone();
+        move |(data, all, onchange)| {
+            let mut next = (*selection).clone();
+            match all {
+                SelectAllRows::Model => next.retain(|key| data.contains(key)),
+                SelectAllRows::Keys(keys) => {
+                    let keys = keys.iter().collect::<HashSet<_>>();
+                    next.retain(|key| keys.contains(key));
+                }
+                SelectAllRows::Callback(_) => {}
+            }
+            onchange.emit(next);
+        }
+    });
+
+    let selected = use_callback(state.clone(), |key: M::Key, state| state.is_selected(&key));
+
+    let onselect = use_callback(
+        (state.clone(), onchange.clone(), keys.clone()),
+        |(key, event): (M::Key, RowSelectEvent), (state, onchange, keys)| {
+            let mut next = (***state).clone();
+            match event.range {
+                true => next.set_range(key, event.selected, keys),
+                false => next.set(key, event.selected),
+            }
+            onchange.emit(next);
+        },
+    );
+
+    let onselectpage = use_callback(
+        (state.clone(), onchange.clone(), keys.clone()),
+        |selected: bool, (state, onchange, keys)| {
+            let mut next = (***state).clone();
+            next.set_all(keys.iter(), selected);
+            onchange.emit(next);
+        },
+    );
+
+    let onselectall = use_callback(
+        (onchange.clone(), onselectpage.clone(), all.clone()),
+        |selected: bool, (onchange, onselectpage, all)| match (all, selected) {
+            (SelectAllRows::Model, _) => onselectpage.emit(selected),
+            (_, false) => onchange.emit(TableSelection::default()),
+            (SelectAllRows::Keys(keys), true) => {
+                let mut next = TableSelection::default();
+                next.set_all(keys.iter(), true);
+                onchange.emit(next);
+            }
+            (SelectAllRows::Callback(callback), true) => callback.emit(()),
+        },
+    );
+
+    let selection = (*state).clone();
+
+    UseTableSelection {
+        state: selection.state(keys.iter()),
+        keys,
+        all,
+        selection,
+        selected,
+        onselect,
+        onselectpage,
+        onselectall,
+        onchange,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys() -> Vec<usize> {
+        (0..10).collect()
+    }
+
+    fn selected(selection: &TableSelection<usize>) -> Vec<usize> {
+        let mut result = selection.iter().copied().collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+
+    #[test]
+    fn test_range() {
+        let keys = keys();
+        let mut selection = TableSelection::default();
+
+        selection.set(2, true);
+        selection.set_range(5, true, &keys);
+        assert_eq!(selected(&selection), vec![2, 3, 4, 5]);
+
+        // the anchor stays, so adjusting the range works
+        selection.set_range(0, true, &keys);
+        assert_eq!(selected(&selection), vec![0, 1, 2, 3, 4, 5]);
+
+        // deselecting a range
+        selection.set(4, false);
+        selection.set_range(1, false, &keys);
+        assert_eq!(selected(&selection), vec![0, 5]);
+    }
+
+    #[test]
+    fn test_range_without_anchor() {
+        let keys = keys();
+        let mut selection = TableSelection::default();
+
+        selection.set_range(5, true, &keys);
+        assert_eq!(selected(&selection), vec![5]);
+
+        // anchor is not part of the current keys
+        let mut selection = TableSelection::default();
+        selection.set(20, true);
+        selection.set_range(5, true, &keys);
+        assert_eq!(selected(&selection), vec![5, 20]);
+    }
+
+    #[test]
+    fn test_state() {
+        let keys = keys();
+        let mut selection = TableSelection::default();
+        assert_eq!(selection.state(&keys), CheckboxState::Unchecked);
+
+        selection.set(3, true);
+        assert_eq!(selection.state(&keys), CheckboxState::Indeterminate);
+
+        selection.set_all(&keys, true);
+        assert_eq!(selection.state(&keys), CheckboxState::Checked);
+        assert_eq!(selection.len(), 10);
+
+        selection.set_all(&keys[0..5], false);
+        assert_eq!(selection.state(&keys[0..5]), CheckboxState::Unchecked);
+        assert_eq!(selection.state(&keys), CheckboxState::Indeterminate);
+    }
+
+    fn use_selection(
+        page: Vec<usize>,
+        all: SelectAllRows<usize>,
+        selected: &[usize],
+    ) -> UseTableSelection<usize> {
+        let mut selection = TableSelection::default();
+        selection.set_all(selected, true);
+        UseTableSelection {
+            keys: Rc::new(page),
+            all,
+            selection: Rc::new(selection),
+            state: CheckboxState::Unchecked,
+            selected: Callback::from(|_| false),
+            onselect: Callback::noop(),
+            onselectpage: Callback::noop(),
+            onselectall: Callback::noop(),
+            onchange: Callback::noop(),
+        }
+    }
+
+    #[test]
+    fn test_selected_keys() {
+        let selected = [1, 3, 7, 9];
+
+        let selection = use_selection(vec![0, 1, 2, 3], SelectAllRows::Model, &selected);
+        assert_eq!(selection.selected_keys(), vec![1, 3]);
+        assert_eq!(selection.total(), Some(4));
+
+        let all = SelectAllRows::Keys(Rc::new(keys()));
+        let selection = use_selection(vec![6, 7, 8, 9], all, &selected);
+        assert_eq!(selection.selected_keys(), vec![1, 3, 7, 9]);
+        assert_eq!(selection.total(), Some(10));
+
+        let all = SelectAllRows::Callback(Callback::noop());
+        let selection = use_selection(vec![9, 8, 7, 6], all, &selected);
+        let keys = selection.selected_keys();
+        assert_eq!(keys[0..2], [9, 7]);
+        assert_eq!(keys.len(), 4);
+        assert_eq!(selection.total(), None);
+        assert!(selection.is_paged());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut selection = TableSelection::default();
+        selection.set_all(&keys(), true);
+        selection.set(8, true);
+
+        selection.retain(|key| *key < 5);
+        assert_eq!(selected(&selection), vec![0, 1, 2, 3, 4]);
+        assert_eq!(selection.anchor, None);
+    }
+}
@@ -0,0 +1,599 @@
+use super::TableDataModel;
+use crate::prelude::{Order, TableHeaderSortBy, TableHeaderSortEvent};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A function comparing two items of a table.
+pub type TableComparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// A set of comparators, one per sortable column.
+pub struct TableComparators<C, T> {
+    comparators: Vec<(C, TableComparator<T>)>,
+}
+
+impl<C, T> Default for TableComparators<C, T> {
+    fn default() -> Self {
+        Self {
+            comparators: Vec::new(),
+        }
+    }
+}
+
+impl<C, T> Clone for TableComparators<C, T>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            comparators: self.comparators.clone(),
+        }
+    }
+}
+
+impl<C, T> TableComparators<C, T>
+where
+    C: Clone + Eq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a comparator for a column.
+    ///
+    /// Adding a comparator for a column a second time replaces the previous one.
+    pub fn with<F>(mut self, column: C, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        self.comparators.retain(|(c, _)| c != &column);
+        self.comparators.push((column, Rc::new(f)));
+        self
+    }
+
+    /// Add a comparator for a column, comparing by a key extracted from the item.
+    pub fn with_key<F, K>(self, column: C, f: F) -> Self
+    where
+        F: Fn(&T) -> K + 'static,
+        K: Ord,
+    {
+        self.with(column, move |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Get the comparator for a column.
+    pub fn get(&self, column: &C) -> Option<&TableComparator<T>> {
+        self.comparators
+            .iter()
+            .find_map(|(c, f)| (c == column).then_some(f))
+    }
+
+    /// Compare two items according to the sort criteria.
+    ///
+    /// Columns without a comparator are ignored.
+    pub fn compare(&self, criteria: &TableSortCriteria<C>, a: &T, b: &T) -> Ordering {
+        for sort_by in criteria.iter() {
+            let Some(f) = self.get(&sort_by.index) else {
+                continue;
+            };
+
+            let result = match sort_by.order {
+                Order::Ascending => f(a, b),
+                Order::Descending => f(b, a),
+            };
+
+            if result != Ordering::Equal {
+                return result;
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    /// Evaluate the sorted order of a list of items.
+    ///
+    /// The result is a list of indices into the provided items. Sorting is stable, so items
+    /// considered equal keep their original order.
+    pub fn sort_order(&self, criteria: &TableSortCriteria<C>, items: &[&T]) -> Vec<usize> {
+        let mut order = (0..items.len()).collect::<Vec<_>>();
+        if !criteria.is_empty() {
+            order.sort_by(|a, b| self.compare(criteria, items[*a], items[*b]));
+        }
+        order
+    }
+}
+
+/// The criteria to sort a table by, in order of priority.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSortCriteria<C>(Vec<TableHeaderSortBy<C>>)
+where
+    C: Clone + Eq;
+
+impl<C> Default for TableSortCriteria<C>
+where
+    C: Clone + Eq,
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<C> From<TableHeaderSortBy<C>> for TableSortCriteria<C>
+where
+    C: Clone + Eq,
+{
+    fn from(value: TableHeaderSortBy<C>) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<C> From<Vec<TableHeaderSortBy<C>>> for TableSortCriteria<C>
+where
+    C: Clone + Eq,
+{
+    fn from(value: Vec<TableHeaderSortBy<C>>) -> Self {
+        let mut result = Self::default();
+        for sort_by in value {
+            result.extend(sort_by);
+        }
+        result
+    }
+}
+
+impl<C> TableSortCriteria<C>
+where
+    C: Clone + Eq,
+{
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TableHeaderSortBy<C>> {
+        self.0.iter()
+    }
+
+    /// Get the sort state of a column, if the column is part of the criteria.
+    pub fn get(&self, column: &C) -> Option<TableHeaderSortBy<C>> {
+        self.0.iter().find(|s| &s.index == column).cloned()
+    }
+
+    /// Get the priority of a column, `0` being the highest priority.
+    pub fn priority(&self, column: &C) -> Option<usize> {
+        self.0.iter().position(|s| &s.index == column)
+    }
+
+    /// Replace the current criteria with a single column.
+    pub fn set(&mut self, sort_by: TableHeaderSortBy<C>) {
+        self.0.clear();
+        self.0.push(sort_by);
+    }
+
+    /// Extend the current criteria with a column.
+    ///
+    /// If the column already is part of the criteria, its order gets updated, keeping its
+    /// priority. Otherwise, it gets added with the lowest priority.
+    pub fn extend(&mut self, sort_by: TableHeaderSortBy<C>) {
+        match self.0.iter_mut().find(|s| s.index == sort_by.index) {
+            Some(current) => current.order = sort_by.order,
+            None => self.0.push(sort_by),
+        }
+    }
+
+    /// Apply a sort event, as emitted by a column.
+    pub fn apply(&mut self, event: TableHeaderSortEvent<C>) {
+        match event.extend {
+            true => self.extend(event.sort_by),
+            false => self.set(event.sort_by),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// A [`TableDataModel`] sorting another one.
+///
+/// The keys of the wrapped model are passed through unchanged. So any state attached to keys
+/// (like the expansion state of [`super::use_table_data`]) stays with its row, even when sorting
+/// changes.
+pub struct SortedTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    _marker: PhantomData<C>,
+    model: M,
+    order: Rc<Vec<usize>>,
+}
+
+impl<C, M> SortedTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    pub fn new(
+        model: M,
+        criteria: &TableSortCriteria<C>,
+        comparators: &TableComparators<C, M::Item>,
+    ) -> Self {
+        let order = {
+            let items = model.iter().map(|(_, item)| item).collect::<Vec<_>>();
+            comparators.sort_order(criteria, &items)
+        };
+
+        Self {
+            _marker: Default::default(),
+            model,
+            order: Rc::new(order),
+        }
+    }
+}
+
+impl<C, M> Clone for SortedTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _marker: Default::default(),
+            model: self.model.clone(),
+            order: self.order.clone(),
+        }
+    }
+}
+
+impl<C, M> PartialEq for SortedTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.order, &other.order) && self.model == other.model
+    }
+}
+
+impl<C, M> TableDataModel<C> for SortedTableModel<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + 'static,
+{
+    type Iterator<'i> = std::vec::IntoIter<(Self::Key, &'i Self::Item)>;
+    type Item = M::Item;
+    type Key = M::Key;
+
+    fn len(&self) -> usize {
+        self.model.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.model.is_empty()
+    }
+
+    fn contains(&self, key: &Self::Key) -> bool {
+        self.model.contains(key)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        let mut entries = self.model.iter().map(Some).collect::<Vec<_>>();
+        self.order
+            .iter()
+            .filter_map(|index| entries.get_mut(*index).and_then(Option::take))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// The result of [`use_table_sorting`].
+pub struct UseTableSorting<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    /// The sorted model
+    pub model: SortedTableModel<C, M>,
+    /// The current sort criteria
+    pub criteria: UseStateHandle<TableSortCriteria<C>>,
+    /// Callback for the `onsortevent` property of a [`crate::prelude::TableColumn`]
+    pub onsort: Callback<TableHeaderSortEvent<C>>,
+}
+
+impl<C, M> UseTableSorting<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C>,
+{
+    /// Get the sort state of a column, for the `sortby` property of a [`crate::prelude::TableColumn`].
+    pub fn sortby(&self, column: &C) -> Option<TableHeaderSortBy<C>> {
+        self.criteria.get(column)
+    }
+}
+
+impl<C, M> Clone for UseTableSorting<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            criteria: self.criteria.clone(),
+            onsort: self.onsort.clone(),
+        }
+    }
+}
+
+impl<C, M> PartialEq for UseTableSorting<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TableDataModel<C> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.model == other.model && self.criteria == other.criteria && self.onsort == other.onsort
+    }
+}
+
+/// Sort the data of a table, on the client side.
+///
+/// This hook takes a [`TableDataModel`] and a set of comparators, and returns a sorted model,
+/// which can then be handed over to [`super::use_table_data`]. Sorting is stable and can use
+/// multiple columns. Clicking a column header replaces the current criteria, shift-clicking it
+/// adds the column with a lower priority.
+///
+/// The comparators will be initialized once, using the initializer function.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq)]
+/// enum Column { Name, Size };
+/// #[derive(Clone)]
+/// struct ExampleEntry { name: String, size: usize };
+///
+/// impl TableEntryRenderer<Column> for ExampleEntry {
+///   fn render_cell(&self, context: CellContext<'_, Column>) -> Cell {
+///     match context.column {
+///       Column::Name => html!(&self.name).into(),
+///       Column::Size => html!({self.size}).into(),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let entries = use_memo((), |()| {
+///       vec![
+///           ExampleEntry { name: "foo".into(), size: 3 },
+///           ExampleEntry { name: "bar".into(), size: 5 },
+///       ]
+///   });
+///
+///   let sorting = use_table_sorting(MemoizedTableModel::new(entries), || {
+///       TableComparators::new()
+///           .with_key(Column::Name, |entry: &ExampleEntry| entry.name.clone())
+///           .with_key(Column::Size, |entry: &ExampleEntry| entry.size)
+///   });
+///   let (entries, onexpand) = use_table_data(sorting.model.clone());
+///
+///   let header = html_nested! {
+///     <TableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name}
+///         sortby={sorting.sortby(&Column::Name)} onsortevent={sorting.onsort.clone()} />
+///       <TableColumn<Column> label="Size" index={Column::Size}
+///         sortby={sorting.sortby(&Column::Size)} onsortevent={sorting.onsort.clone()} />
+///     </TableHeader<Column>>
+///   };
+///
+///   html! (
+///     <Table<Column, UseTableData<Column, SortedTableModel<Column, MemoizedTableModel<ExampleEntry>>>>
+///       {header}
+///       {entries}
+///       {onexpand}
+///     />
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_sorting<C, M, F>(data: M, init: F) -> UseTableSorting<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + Clone + TableDataModel<C> + 'static,
+    F: FnOnce() -> TableComparators<C, M::Item>,
+{
+    let comparators = use_memo((), |()| init());
+    let criteria = use_state_eq(TableSortCriteria::<C>::default);
+
+    let model = use_memo((data, (*criteria).clone()), move |(data, criteria)| {
+        SortedTableModel::new(data.clone(), criteria, &comparators)
+    });
+
+    let onsort = use_callback(
+        criteria.clone(),
+        |event: TableHeaderSortEvent<C>, criteria| {
+            let mut next = (**criteria).clone();
+            next.apply(event);
+            criteria.set(next);
+        },
+    );
+
+    UseTableSorting {
+        model: (*model).clone(),
+        criteria,
+        onsort,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Column {
+        Name,
+        Size,
+        Other,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Item(&'static str, usize);
+
+    fn comparators() -> TableComparators<Column, Item> {
+        TableComparators::new()
+            .with_key(Column::Name, |item: &Item| item.0)
+            .with_key(Column::Size, |item: &Item| item.1)
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item("b", 2),
+            Item("a", 2),
+            Item("c", 1),
+            Item("a", 1),
+            Item("b", 1),
+        ]
+    }
+
+    fn sort(criteria: &TableSortCriteria<Column>) -> Vec<Item> {
+        let items = items();
+        let refs = items.iter().collect::<Vec<_>>();
+        comparators()
+            .sort_order(criteria, &refs)
+            .into_iter()
+            .map(|i| items[i].clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(sort(&Default::default()), items());
+    }
+
+    #[test]
+    fn test_single() {
+        let criteria = TableHeaderSortBy::ascending(Column::Name).into();
+        assert_eq!(
+            sort(&criteria),
+            vec![
+                Item("a", 2),
+                Item("a", 1),
+                Item("b", 2),
+                Item("b", 1),
+                Item("c", 1),
+            ]
+        );
+
+        let criteria = TableHeaderSortBy::descending(Column::Size).into();
+        assert_eq!(
+            sort(&criteria),
+            vec![
+                Item("b", 2),
+                Item("a", 2),
+                Item("c", 1),
+                Item("a", 1),
+                Item("b", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi() {
+        let criteria = vec![
+            TableHeaderSortBy::ascending(Column::Name),
+            TableHeaderSortBy::ascending(Column::Size),
+        ]
+        .into();
+        assert_eq!(
+            sort(&criteria),
+            vec![
+                Item("a", 1),
+                Item("a", 2),
+                Item("b", 1),
+                Item("b", 2),
+                Item("c", 1),
+            ]
+        );
+
+        let criteria = vec![
+            TableHeaderSortBy::ascending(Column::Size),
+            TableHeaderSortBy::descending(Column::Name),
+        ]
+        .into();
+        assert_eq!(
+            sort(&criteria),
+            vec![
+                Item("c", 1),
+                Item("b", 1),
+                Item("a", 1),
+                Item("b", 2),
+                Item("a", 2),
+            ]
+        );
+    }
+
+    /// columns without a comparator must not affect the order
+    #[test]
+    fn test_missing_comparator() {
+        let criteria = vec![
+            TableHeaderSortBy::ascending(Column::Other),
+            TableHeaderSortBy::ascending(Column::Size),
+        ]
+        .into();
+        assert_eq!(
+            sort(&criteria),
+            vec![
+                Item("c", 1),
+                Item("a", 1),
+                Item("b", 1),
+                Item("b", 2),
+                Item("a", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut criteria = TableSortCriteria::default();
+
+        criteria.apply(TableHeaderSortEvent {
+            sort_by: TableHeaderSortBy::ascending(Column::Name),
+            extend: false,
+        });
+        assert_eq!(criteria.priority(&Column::Name), Some(0));
+
+        criteria.apply(TableHeaderSortEvent {
+            sort_by: TableHeaderSortBy::ascending(Column::Size),
+            extend: true,
+        });
+        assert_eq!(criteria.priority(&Column::Name), Some(0));
+        assert_eq!(criteria.priority(&Column::Size), Some(1));
+
+        // changing the order keeps the priority
+        criteria.apply(TableHeaderSortEvent {
+            sort_by: TableHeaderSortBy::descending(Column::Name),
+            extend: true,
+        });
+        assert_eq!(criteria.len(), 2);
+        assert_eq!(
+            criteria.get(&Column::Name),
+            Some(TableHeaderSortBy::descending(Column::Name))
+        );
+        assert_eq!(criteria.priority(&Column::Name), Some(0));
+
+        // a plain click replaces everything
+        criteria.apply(TableHeaderSortEvent {
+            sort_by: TableHeaderSortBy::ascending(Column::Size),
+            extend: false,
+        });
+        assert_eq!(criteria.len(), 1);
+        assert_eq!(criteria.get(&Column::Name), None);
+        assert_eq!(criteria.priority(&Column::Size), Some(0));
+    }
+}
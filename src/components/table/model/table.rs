@@ -1,11 +1,170 @@
 use super::{TableDataModel, TableModelEntry};
 use crate::prelude::ExpansionState;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use yew::virtual_dom::Key;
+use yew::Callback;
+
+/// The subscribers of each row, by the key of the row.
+type Subscribers<C> = HashMap<Key, Vec<(usize, Callback<Option<ExpansionState<C>>>)>>;
+
+/// Subscriptions to the expansion state of individual rows.
+///
+/// This allows rows to re-render when their own expansion state changes, without the need to
+/// re-render the whole table.
+pub struct ExpansionSubscribers<K, C>
+where
+    C: Clone + Eq,
+{
+    subscribers: Rc<RefCell<Subscribers<C>>>,
+    next_id: Rc<Cell<usize>>,
+    _marker: PhantomData<K>,
+}
+
+impl<K, C> Default for ExpansionSubscribers<K, C>
+where
+    C: Clone + Eq,
+{
+    fn default() -> Self {
+        Self {
+            subscribers: Default::default(),
+            next_id: Default::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, C> Clone for ExpansionSubscribers<K, C>
+where
+    C: Clone + Eq,
+{
+    fn clone(&self) -> Self {
+        Self {
+            subscribers: self.subscribers.clone(),
+            next_id: self.next_id.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, C> PartialEq for ExpansionSubscribers<K, C>
+where
+    C: Clone + Eq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.subscribers, &other.subscribers)
+    }
+}
+
+impl<K, C> Debug for ExpansionSubscribers<K, C>
+where
+    C: Clone + Eq,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpansionSubscribers")
+            .field(
+                "subscribers",
+                &self
+                    .subscribers
+                    .borrow()
+                    .values()
+                    .map(Vec::len)
+                    .sum::<usize>(),
+            )
+            .finish()
+    }
+}
+
+impl<K, C> ExpansionSubscribers<K, C>
+where
+    K: Into<Key> + 'static,
+    C: Clone + Eq + 'static,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe to changes of the expansion state of a row.
+    ///
+    /// The subscription is active until the returned handle is dropped.
+    pub fn subscribe(
+        &self,
+        key: K,
+        callback: Callback<Option<ExpansionState<C>>>,
+    ) -> ExpansionSubscription<K, C> {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1));
+
+        let key = key.into();
+        self.subscribers
+            .borrow_mut()
+            .entry(key.clone())
+            .or_default()
+            .push((id, callback));
+
+        ExpansionSubscription {
+            id,
+            key,
+            subscribers: Rc::downgrade(&self.subscribers),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Notify all subscribers of a row about a new expansion state.
+    pub fn notify(&self, key: &K, state: Option<ExpansionState<C>>)
+    where
+        K: Clone,
+    {
+        // collect first, as callbacks might subscribe or unsubscribe
+        let callbacks = self
+            .subscribers
+            .borrow()
+            .get(&key.clone().into())
+            .map(|subscribers| {
+                subscribers
+                    .iter()
+                    .map(|(_, callback)| callback.clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for callback in callbacks {
+            callback.emit(state.clone());
+        }
+    }
+}
+
+/// A subscription to the expansion state of a row, unsubscribes when dropped.
+pub struct ExpansionSubscription<K, C>
+where
+    C: Clone + Eq,
+{
+    id: usize,
+    key: Key,
+    subscribers: Weak<RefCell<Subscribers<C>>>,
+    _marker: PhantomData<K>,
+}
+
+impl<K, C> Drop for ExpansionSubscription<K, C>
+where
+    C: Clone + Eq,
+{
+    fn drop(&mut self) {
+        if let Some(subscribers) = self.subscribers.upgrade() {
+            let mut subscribers = subscribers.borrow_mut();
+            if let Some(row) = subscribers.get_mut(&self.key) {
+                row.retain(|(id, _)| *id != self.id);
+                if row.is_empty() {
+                    subscribers.remove(&self.key);
+                }
+            }
+        }
+    }
+}
 
 /// A [`super::TableModel`] based on a [`TableDataModel`] plus additional state.
 pub struct StateModel<C, M>
@@ -16,6 +175,7 @@ where
     _marker: PhantomData<C>,
     model: M,
     state: Rc<RefCell<HashMap<M::Key, ExpansionState<C>>>>,
+    subscribers: Option<ExpansionSubscribers<M::Key, C>>,
 }
 
 impl<C, M> StateModel<C, M>
@@ -27,9 +187,16 @@ where
         Self {
             model,
             state,
+            subscribers: None,
             _marker: Default::default(),
         }
     }
+
+    /// Provide subscriptions for rows, notified when the expansion state changes.
+    pub fn with_subscribers(mut self, subscribers: ExpansionSubscribers<M::Key, C>) -> Self {
+        self.subscribers = Some(subscribers);
+        self
+    }
 }
 
 impl<C, M> PartialEq for StateModel<C, M>
@@ -39,7 +206,9 @@ where
     M::Key: Hash,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.state == other.state && self.model == other.model
+        self.state == other.state
+            && self.subscribers == other.subscribers
+            && self.model == other.model
     }
 }
 
@@ -72,6 +241,10 @@ where
             }
         }))
     }
+
+    fn expansion_subscribers(&self) -> Option<ExpansionSubscribers<Self::Key, C>> {
+        self.subscribers.clone()
+    }
 }
 
 pub struct StateModelIter<'i, K, V, C>(Box<dyn Iterator<Item = TableModelEntry<'i, V, K, C>> + 'i>)
@@ -103,3 +276,30 @@ where
         self.0.next()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_subscribers() {
+        let subscribers = ExpansionSubscribers::<usize, ()>::new();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let subscription = subscribers.subscribe(1, {
+            let received = received.clone();
+            Callback::from(move |state| received.borrow_mut().push(state))
+        });
+
+        subscribers.notify(&0, Some(ExpansionState::Row));
+        subscribers.notify(&1, Some(ExpansionState::Row));
+        subscribers.notify(&1, None);
+        assert_eq!(*received.borrow(), vec![Some(ExpansionState::Row), None]);
+
+        // dropping the subscription unsubscribes
+        drop(subscription);
+        subscribers.notify(&1, Some(ExpansionState::Row));
+        assert_eq!(received.borrow().len(), 2);
+        assert!(subscribers.subscribers.borrow().is_empty());
+    }
+}
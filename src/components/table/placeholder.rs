@@ -0,0 +1,88 @@
+use super::{TableBody, TableData, TableRow};
+use crate::prelude::{Action, Bullseye, EmptyState, Icon, Size, Skeleton};
+use yew::prelude::*;
+
+/// Content shown by a [`super::Table`] instead of its entries.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TablePlaceholder {
+    /// Data is being loaded, showing a number of placeholder rows.
+    Loading { rows: usize },
+    /// There is no data to show.
+    Empty(Html),
+    /// Loading the data failed.
+    Error {
+        message: String,
+        onretry: Option<Callback<()>>,
+    },
+}
+
+impl TablePlaceholder {
+    pub fn loading(rows: usize) -> Self {
+        Self::Loading { rows }
+    }
+
+    /// The default empty state, stating that no results were found.
+    pub fn empty() -> Self {
+        Self::Empty(html!(
+            <EmptyState
+                title="No results found"
+                icon={Icon::Search}
+                size={Size::Small}
+            >
+                { "No results match the criteria. Clear all filters and try again." }
+            </EmptyState>
+        ))
+    }
+
+    pub fn error(message: impl Into<String>, onretry: Option<Callback<()>>) -> Self {
+        Self::Error {
+            message: message.into(),
+            onretry,
+        }
+    }
+
+    /// Render the placeholder, as table body spanning a number of columns.
+    pub(crate) fn render(&self, columns: usize) -> Html {
+        match self {
+            Self::Loading { rows } => html!(
+                <TableBody>
+                    { for (0..*rows).map(|_| html!(
+                        <TableRow>
+                            { for (0..columns).map(|_| html!(
+                                <TableData>
+                                    <Skeleton screenreader_text="Loading" />
+                                </TableData>
+                            )) }
+                        </TableRow>
+                    )) }
+                </TableBody>
+            ),
+            Self::Empty(content) => Self::render_full(columns, content.clone()),
+            Self::Error { message, onretry } => Self::render_full(
+                columns,
+                html!(
+                    <EmptyState
+                        title="Unable to load data"
+                        icon={Icon::ExclamationCircle}
+                        size={Size::Small}
+                        primary={onretry.clone().map(|onretry| Action::new("Retry", onretry))}
+                    >
+                        { message }
+                    </EmptyState>
+                ),
+            ),
+        }
+    }
+
+    fn render_full(columns: usize, content: Html) -> Html {
+        html!(
+            <TableBody>
+                <TableRow>
+                    <TableData colspan={columns}>
+                        <Bullseye>{ content }</Bullseye>
+                    </TableData>
+                </TableRow>
+            </TableBody>
+        )
+    }
+}
@@ -1,4 +1,6 @@
-use crate::prelude::{Cell, CellContext, MenuChildVariant, Span};
+use crate::prelude::{
+    Cell, CellContext, EditCellContext, MenuChildVariant, Span, ValidationResult, Validator,
+};
 
 /// Render table entries
 pub trait TableEntryRenderer<C>
@@ -32,10 +34,46 @@ where
         vec![]
     }
 
-    /// Render the row actions.
+    /// Render the row actions, as items of the "kebab" menu.
+    ///
+    /// For actions rendered inline, or actions being disabled or requiring a confirmation, see
+    /// the `row_actions` property of the [`super::Table`].
     ///
     /// Defaults to no actions.
     fn actions(&self) -> Vec<MenuChildVariant> {
         vec![]
     }
 }
+
+/// Edit table entries
+///
+/// Used in combination with [`super::use_table_editing`].
+pub trait TableEntryEditor<C>: TableEntryRenderer<C>
+where
+    C: Clone + Eq + 'static,
+{
+    /// Render the edit widget for the requested column.
+    ///
+    /// This is called on the item being edited, containing all changes so far. Changes are applied
+    /// using [`EditCellContext::update`].
+    ///
+    /// Defaults to rendering the read-only cell.
+    fn render_edit_cell(&self, context: EditCellContext<'_, C, Self>) -> Cell
+    where
+        Self: Sized,
+    {
+        self.render_cell(CellContext {
+            column: context.column,
+        })
+    }
+
+    /// The validator for the requested column.
+    ///
+    /// Defaults to no validation.
+    fn validator(&self, #[allow(unused)] column: &C) -> Validator<Self, ValidationResult>
+    where
+        Self: Sized,
+    {
+        Validator::None
+    }
+}
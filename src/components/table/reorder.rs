@@ -0,0 +1,332 @@
+use std::fmt::{Debug, Formatter};
+use yew::prelude::*;
+
+/// Move an item to a new index, shifting the items in between.
+///
+/// This matches the index reported by the `onreorder` callback of the [`super::Table`]: `to` is
+/// the index of the item after it got moved. Out of range indexes are ignored.
+pub fn reorder_items<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from >= items.len() {
+        return;
+    }
+    let item = items.remove(from);
+    items.insert(to.min(items.len()), item);
+}
+
+/// Evaluate the target index of a row, moved by a key press.
+///
+/// Returns `None` if the key doesn't move the row.
+pub(crate) fn keyboard_target(key: &str, index: usize, len: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    match key {
+        "ArrowUp" => Some(index.saturating_sub(1)),
+        "ArrowDown" => Some((index + 1).min(last)),
+        "Home" => Some(0),
+        "End" => Some(last),
+        _ => None,
+    }
+}
+
+/// A row being dragged using the mouse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Drag<K> {
+    key: K,
+    /// The index of the row when the drag started
+    from: usize,
+    /// The index the row would get when being dropped
+    to: usize,
+}
+
+/// The parameters of a draggable row.
+///
+/// Provided by [`UseTableReorder::row_params`], and handed over to the `drag` property of both
+/// the [`super::TableRow`] and the [`super::TableData`] rendering the drag handle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragParams {
+    /// The row is being dragged
+    pub dragged: bool,
+    /// The row is grabbed, using the keyboard
+    pub grabbed: bool,
+    pub ondragstart: Callback<DragEvent>,
+    pub ondragover: Callback<DragEvent>,
+    pub ondrop: Callback<DragEvent>,
+    pub ondragend: Callback<DragEvent>,
+    /// Key events of the drag handle
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// The drag handle lost the focus
+    pub onblur: Callback<FocusEvent>,
+}
+
+/// The result of [`use_table_reorder`].
+pub struct UseTableReorder<K>
+where
+    K: 'static,
+{
+    len: usize,
+    drag: UseStateHandle<Option<Drag<K>>>,
+    grabbed: UseStateHandle<Option<K>>,
+    onreorder: Callback<(K, usize)>,
+}
+
+impl<K> Clone for UseTableReorder<K> {
+    fn clone(&self) -> Self {
+        Self {
+            len: self.len,
+            drag: self.drag.clone(),
+            grabbed: self.grabbed.clone(),
+            onreorder: self.onreorder.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for UseTableReorder<K>
+where
+    K: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.drag == other.drag
+            && self.grabbed == other.grabbed
+            && self.onreorder == other.onreorder
+    }
+}
+
+impl<K> Debug for UseTableReorder<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UseTableReorder")
+            .field("len", &self.len)
+            .field("dragging", &self.drag.is_some())
+            .field("grabbed", &self.grabbed.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K> UseTableReorder<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Check if a row is being dragged using the mouse.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Order the rows like they would be ordered when dropping the dragged row.
+    ///
+    /// The rows must be rendered in this order, using their index in the result for
+    /// [`Self::row_params`].
+    pub fn preview<T>(&self, mut rows: Vec<T>) -> Vec<T> {
+        if let Some(drag) = &*self.drag {
+            reorder_items(&mut rows, drag.from, drag.to);
+        }
+        rows
+    }
+
+    /// Create the parameters for a row, at the provided index.
+    pub fn row_params(&self, key: K, index: usize) -> DragParams {
+        let dragged = self.drag.as_ref().is_some_and(|drag| drag.key == key);
+        let grabbed = self.grabbed.as_ref() == Some(&key);
+
+        let ondragstart = {
+            let (drag, key) = (self.drag.clone(), key.clone());
+            Callback::from(move |evt: DragEvent| {
+                if let Some(transfer) = evt.data_transfer() {
+                    transfer.set_effect_allowed("move");
+                    // some browsers only start dragging when there is data
+                    let _ = transfer.set_data("text/plain", "");
+                }
+                drag.set(Some(Drag {
+                    key: key.clone(),
+                    from: index,
+                    to: index,
+                }));
+            })
+        };
+
+        let ondragover = {
+            let drag = self.drag.clone();
+            Callback::from(move |evt: DragEvent| {
+                // only accept our own rows, not e.g. files
+                let Some(current) = &*drag else {
+                    return;
+                };
+                evt.prevent_default();
+                if let Some(transfer) = evt.data_transfer() {
+                    transfer.set_drop_effect("move");
+                }
+                if current.to != index {
+                    drag.set(Some(Drag {
+                        to: index,
+                        ..current.clone()
+                    }));
+                }
+            })
+        };
+
+        let ondrop = {
+            let (drag, onreorder) = (self.drag.clone(), self.onreorder.clone());
+            Callback::from(move |evt: DragEvent| {
+                let Some(current) = &*drag else {
+                    return;
+                };
+                evt.prevent_default();
+                if current.from != index {
+                    onreorder.emit((current.key.clone(), index));
+                }
+                drag.set(None);
+            })
+        };
+
+        let ondragend = {
+            let drag = self.drag.clone();
+            Callback::from(move |_: DragEvent| drag.set(None))
+        };
+
+        let onkeydown = {
+            let (state, onreorder, len) = (self.grabbed.clone(), self.onreorder.clone(), self.len);
+            Callback::from(move |evt: KeyboardEvent| {
+                match (grabbed, evt.key().as_str()) {
+                    (false, " " | "Enter") => state.set(Some(key.clone())),
+                    (true, " " | "Enter" | "Escape") => state.set(None),
+                    (true, name) => match keyboard_target(name, index, len) {
+                        Some(to) if to != index => onreorder.emit((key.clone(), to)),
+                        Some(_) => {}
+                        None => return,
+                    },
+                    (false, _) => return,
+                }
+                evt.prevent_default();
+            })
+        };
+
+        // release the row when the focus moves on, but not when moving the row drops the focus
+        let onblur = {
+            let state = self.grabbed.clone();
+            Callback::from(move |evt: FocusEvent| {
+                if grabbed && evt.related_target().is_some() {
+                    state.set(None);
+                }
+            })
+        };
+
+        DragParams {
+            dragged,
+            grabbed,
+            ondragstart,
+            ondragover,
+            ondrop,
+            ondragend,
+            onkeydown,
+            onblur,
+        }
+    }
+}
+
+/// Reorder the rows of a table, using drag and drop.
+///
+/// Rows can be dragged using the mouse, or grabbed using the keyboard: pressing space (or enter)
+/// on the drag handle grabs the row, the arrow keys (as well as home and end) move it, pressing
+/// space, enter, or escape releases it again. While dragging using the mouse, the rows are shown
+/// in the order they would have when dropping the row. Using the keyboard, each move is reported
+/// right away.
+///
+/// Changes are reported to `onreorder` as the key of the moved row, and its new index. Also see
+/// [`reorder_items`].
+///
+/// This hook is used by the [`super::Table`] when setting the `onreorder` property. Using it
+/// directly allows reordering the rows of a [`super::ComposableTable`]:
+///
+/// * Render the rows in the order of [`UseTableReorder::preview`].
+/// * Hand over the [`DragParams`] of a row to the `drag` property of its [`super::TableRow`].
+/// * Render the drag handle by handing over the same parameters to the `drag` property of a
+///   [`super::TableData`].
+/// * Render an empty header cell for the drag handles, e.g. using the `draggable` property of
+///   the [`super::TableHeader`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let rules = use_state(|| vec!["allow", "log", "deny"]);
+///
+///   let onreorder = use_callback(rules.clone(), |(key, to): (&'static str, usize), rules| {
+///     let mut next = (**rules).clone();
+///     if let Some(from) = next.iter().position(|rule| *rule == key) {
+///       reorder_items(&mut next, from, to);
+///       rules.set(next);
+///     }
+///   });
+///   let reorder = use_table_reorder(rules.len(), onreorder);
+///
+///   html!(
+///     <ComposableTable>
+///       <TableBody>
+///         { for reorder.preview((*rules).clone()).into_iter().enumerate().map(|(index, rule)| {
+///           let drag = reorder.row_params(rule, index);
+///           html!(
+///             <TableRow key={rule} drag={drag.clone()}>
+///               <TableData {drag} />
+///               <TableData>{ rule }</TableData>
+///             </TableRow>
+///           )
+///         }) }
+///       </TableBody>
+///     </ComposableTable>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_reorder<K>(len: usize, onreorder: Callback<(K, usize)>) -> UseTableReorder<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    let drag = use_state_eq(|| None);
+    let grabbed = use_state_eq(|| None);
+
+    UseTableReorder {
+        len,
+        drag,
+        grabbed,
+        onreorder,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reordered(from: usize, to: usize) -> Vec<char> {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        reorder_items(&mut items, from, to);
+        items
+    }
+
+    #[test]
+    fn test_reorder_items() {
+        assert_eq!(reordered(0, 2), vec!['b', 'c', 'a', 'd']);
+        assert_eq!(reordered(3, 0), vec!['d', 'a', 'b', 'c']);
+        assert_eq!(reordered(1, 1), vec!['a', 'b', 'c', 'd']);
+        assert_eq!(reordered(1, 3), vec!['a', 'c', 'd', 'b']);
+    }
+
+    #[test]
+    fn test_reorder_out_of_range() {
+        assert_eq!(reordered(4, 0), vec!['a', 'b', 'c', 'd']);
+        assert_eq!(reordered(0, 10), vec!['b', 'c', 'd', 'a']);
+    }
+
+    #[test]
+    fn test_keyboard_target() {
+        assert_eq!(keyboard_target("ArrowUp", 2, 4), Some(1));
+        assert_eq!(keyboard_target("ArrowUp", 0, 4), Some(0));
+        assert_eq!(keyboard_target("ArrowDown", 2, 4), Some(3));
+        assert_eq!(keyboard_target("ArrowDown", 3, 4), Some(3));
+        assert_eq!(keyboard_target("Home", 2, 4), Some(0));
+        assert_eq!(keyboard_target("End", 1, 4), Some(3));
+        assert_eq!(keyboard_target("a", 1, 4), None);
+        assert_eq!(keyboard_target("ArrowUp", 0, 0), None);
+    }
+}
@@ -0,0 +1,249 @@
+use gloo_events::{EventListener, EventListenerOptions};
+use gloo_utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// The width of a column, as part of [`TableColumnWidths`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TableColumnWidth<C> {
+    pub index: C,
+    /// The width, in pixels
+    pub width: u32,
+}
+
+/// The widths of the resized columns of a table.
+///
+/// Columns which are not part of the widths keep their natural width. Like the
+/// [`super::TableColumnLayout`], this can be persisted, ignoring columns which are no longer part
+/// of the table.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TableColumnWidths<C> {
+    pub columns: Vec<TableColumnWidth<C>>,
+}
+
+impl<C> Default for TableColumnWidths<C> {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl<C> TableColumnWidths<C>
+where
+    C: Clone + Eq + 'static,
+{
+    /// Get the width of a column, `None` if it was not resized.
+    pub fn get(&self, index: &C) -> Option<u32> {
+        self.columns
+            .iter()
+            .find(|c| &c.index == index)
+            .map(|c| c.width)
+    }
+
+    /// Set the width of a column.
+    pub fn set(&mut self, index: C, width: u32) {
+        match self.columns.iter_mut().find(|c| c.index == index) {
+            Some(column) => column.width = width,
+            None => self.columns.push(TableColumnWidth { index, width }),
+        }
+    }
+
+    /// Reset a column to its natural width.
+    pub fn reset(&mut self, index: &C) {
+        self.columns.retain(|c| &c.index != index);
+    }
+}
+
+/// The default minimum width of a resizable column, in pixels.
+pub const DEFAULT_MIN_COLUMN_WIDTH: u32 = 40;
+
+/// Resize a column by an offset, keeping the minimum width.
+pub(crate) fn resized(width: u32, delta: i32, min: u32) -> u32 {
+    let width = (width as i64 + delta as i64).clamp(0, u32::MAX as i64) as u32;
+    width.max(min)
+}
+
+/// The change of width, resizing a column using the keyboard.
+///
+/// Holding the shift key resizes in larger steps.
+pub(crate) fn keyboard_delta(key: &str, shift: bool) -> Option<i32> {
+    let step = match shift {
+        true => 50,
+        false => 10,
+    };
+    match key {
+        "ArrowLeft" => Some(-step),
+        "ArrowRight" => Some(step),
+        _ => None,
+    }
+}
+
+/// The widths of the columns of a header, shared with its columns.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ColumnResizeContext<C>
+where
+    C: Clone + Eq + 'static,
+{
+    pub(crate) widths: TableColumnWidths<C>,
+    pub(crate) onresize: Callback<(C, u32)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub(crate) struct ResizeHandleProperties {
+    /// The header cell of the column
+    pub target: NodeRef,
+    pub width: Option<u32>,
+    pub min: u32,
+    pub label: Option<String>,
+    /// The width while dragging, `None` when dragging ended
+    pub onmove: Callback<Option<u32>>,
+    /// The final width
+    pub onresize: Callback<u32>,
+}
+
+/// The start of dragging the handle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DragStart {
+    x: i32,
+    width: u32,
+}
+
+/// The handle of a resizable column, at its end.
+#[function_component(ResizeHandle)]
+pub(crate) fn resize_handle(props: &ResizeHandleProperties) -> Html {
+    let drag = use_state_eq(|| None::<DragStart>);
+
+    // the current width of the column, as rendered
+    let measure = {
+        let target = props.target.clone();
+        move || {
+            target
+                .cast::<Element>()
+                .map(|th| th.get_bounding_client_rect().width().round() as u32)
+                .unwrap_or_default()
+        }
+    };
+
+    // track the mouse while dragging, on the whole document
+    {
+        let (onmove, onresize, min) = (props.onmove.clone(), props.onresize.clone(), props.min);
+        let drag_state = drag.clone();
+        use_effect_with(*drag, move |drag| {
+            let listeners = drag.map(|start| {
+                let mousemove = {
+                    let onmove = onmove.clone();
+                    EventListener::new_with_options(
+                        &document(),
+                        "mousemove",
+                        EventListenerOptions::enable_prevent_default(),
+                        move |evt| {
+                            if let Some(evt) = evt.dyn_ref::<MouseEvent>() {
+                                evt.prevent_default();
+                                onmove.emit(Some(resized(
+                                    start.width,
+                                    evt.client_x() - start.x,
+                                    min,
+                                )));
+                            }
+                        },
+                    )
+                };
+                let mouseup = EventListener::new(&document(), "mouseup", move |evt| {
+                    // a click without moving the pointer doesn't resize the column
+                    if let Some(delta) = evt
+                        .dyn_ref::<MouseEvent>()
+                        .map(|evt| evt.client_x() - start.x)
+                        .filter(|delta| *delta != 0)
+                    {
+                        onresize.emit(resized(start.width, delta, min));
+                    }
+                    onmove.emit(None);
+                    drag_state.set(None);
+                });
+                (mousemove, mouseup)
+            });
+            move || drop(listeners)
+        });
+    }
+
+    let onmousedown = {
+        let (drag, measure) = (drag.clone(), measure.clone());
+        Callback::from(move |evt: MouseEvent| {
+            if evt.button() != 0 {
+                return;
+            }
+            // neither select text, nor sort the column
+            evt.prevent_default();
+            evt.stop_propagation();
+            drag.set(Some(DragStart {
+                x: evt.client_x(),
+                width: measure(),
+            }));
+        })
+    };
+
+    let onkeydown = {
+        let (width, min, onresize) = (props.width, props.min, props.onresize.clone());
+        Callback::from(move |evt: KeyboardEvent| {
+            if let Some(delta) = keyboard_delta(&evt.key(), evt.shift_key()) {
+                evt.prevent_default();
+                onresize.emit(resized(width.unwrap_or_else(&measure), delta, min));
+            }
+        })
+    };
+
+    // don't sort the column
+    let onclick = Callback::from(|evt: MouseEvent| evt.stop_propagation());
+
+    html!(
+        <div
+            role="separator"
+            tabindex="0"
+            aria-orientation="vertical"
+            aria-label={props.label.as_ref().map(|label| format!("Resize {label}")).unwrap_or_else(|| "Resize column".into())}
+            aria-valuenow={props.width.map(|width| width.to_string())}
+            aria-valuemin={props.min.to_string()}
+            style="position: absolute; top: 0; bottom: 0; right: 0; width: 8px; cursor: col-resize;"
+            {onmousedown}
+            {onkeydown}
+            {onclick}
+        />
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resized() {
+        assert_eq!(resized(100, 20, 40), 120);
+        assert_eq!(resized(100, -50, 40), 50);
+        assert_eq!(resized(100, -80, 40), 40);
+        assert_eq!(resized(10, -80, 0), 0);
+    }
+
+    #[test]
+    fn test_keyboard_delta() {
+        assert_eq!(keyboard_delta("ArrowLeft", false), Some(-10));
+        assert_eq!(keyboard_delta("ArrowRight", true), Some(50));
+        assert_eq!(keyboard_delta("ArrowUp", false), None);
+    }
+
+    #[test]
+    fn test_widths() {
+        let mut widths = TableColumnWidths::default();
+        widths.set("a", 100);
+        widths.set("b", 50);
+        widths.set("a", 120);
+        assert_eq!(widths.get(&"a"), Some(120));
+        assert_eq!(widths.get(&"b"), Some(50));
+        assert_eq!(widths.get(&"c"), None);
+
+        widths.reset(&"a");
+        assert_eq!(widths.get(&"a"), None);
+        assert_eq!(widths.columns.len(), 1);
+    }
+}
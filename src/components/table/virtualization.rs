@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// The height of the rows of a virtualized table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All rows have the same, fixed height (in pixels).
+    Fixed(f64),
+    /// Rows have an estimated height (in pixels), the actual height is measured once rendered.
+    ///
+    /// This is required when rows can have different heights, e.g. when using expandable rows.
+    Estimated(f64),
+}
+
+impl RowHeight {
+    fn height(&self) -> f64 {
+        match self {
+            Self::Fixed(height) | Self::Estimated(height) => height.max(1.0),
+        }
+    }
+}
+
+/// Settings for rendering only the visible rows of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableVirtualization {
+    /// The height of the rows.
+    pub row_height: RowHeight,
+    /// The number of rows to render before and after the visible rows.
+    pub overscan: usize,
+    /// The height of the scroll container, as CSS value.
+    pub height: AttrValue,
+}
+
+impl TableVirtualization {
+    pub fn new(row_height: RowHeight) -> Self {
+        Self {
+            row_height,
+            overscan: 10,
+            height: "600px".into(),
+        }
+    }
+
+    pub fn fixed(row_height: f64) -> Self {
+        Self::new(RowHeight::Fixed(row_height))
+    }
+
+    pub fn estimated(row_height: f64) -> Self {
+        Self::new(RowHeight::Estimated(row_height))
+    }
+
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<AttrValue>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+/// The window of rows to render.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VirtualWindow {
+    /// The index of the first row to render.
+    pub start: usize,
+    /// The index after the last row to render.
+    pub end: usize,
+    /// The height of the space before the first row.
+    pub before: f64,
+    /// The height of the space after the last row.
+    pub after: f64,
+}
+
+impl VirtualWindow {
+    /// A window showing all rows.
+    pub fn all(count: usize) -> Self {
+        Self {
+            start: 0,
+            end: count,
+            before: 0.0,
+            after: 0.0,
+        }
+    }
+
+    /// Evaluate the window for the current scroll position.
+    ///
+    /// The function `height` provides the height of a row, by its index.
+    pub fn evaluate<F>(
+        count: usize,
+        height: F,
+        scroll_top: f64,
+        viewport: f64,
+        overscan: usize,
+    ) -> Self
+    where
+        F: Fn(usize) -> f64,
+    {
+        Self::from_offsets(&offsets(count, height), scroll_top, viewport, overscan)
+    }
+
+    /// Evaluate the window, based on the offsets of the rows, see [`offsets`].
+    fn from_offsets(offsets: &[f64], scroll_top: f64, viewport: f64, overscan: usize) -> Self {
+        let count = offsets.len() - 1;
+
+        // the first row ending after the scroll position
+        let first = offsets[1..].partition_point(|end| *end <= scroll_top);
+        // the first row starting after the viewport
+        let last = offsets[..count].partition_point(|start| *start < scroll_top + viewport);
+
+        // when scrolled beyond the end, keep showing the last rows
+        let first = match first < count {
+            true => first,
+            false => count.saturating_sub(1),
+        };
+
+        let start = first.saturating_sub(overscan);
+        let end = last.saturating_add(overscan).min(count).max(start);
+
+        Self {
+            start,
+            end,
+            before: offsets[start],
+            after: offsets[count] - offsets[end],
+        }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// The result of [`use_table_virtualization`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseTableVirtualization {
+    /// The active settings, `None` if virtualization is disabled.
+    pub settings: Option<TableVirtualization>,
+    /// The rows to render.
+    pub window: VirtualWindow,
+    /// The scroll container.
+    pub container: NodeRef,
+    /// Callback for scroll events of the container.
+    pub onscroll: Callback<Event>,
+    before: NodeRef,
+    after: NodeRef,
+}
+
+impl UseTableVirtualization {
+    pub fn is_enabled(&self) -> bool {
+        self.settings.is_some()
+    }
+
+    /// Render the spacer before the rows, as part of a table body.
+    pub fn before_row(&self) -> Html {
+        self.spacer_row(self.before.clone(), self.window.before)
+    }
+
+    /// Render the spacer after the rows, as part of a table body.
+    pub fn after_row(&self) -> Html {
+        self.spacer_row(self.after.clone(), self.window.after)
+    }
+
+    /// Render the spacer before the rows, as a table body of its own.
+    ///
+    /// This is required when each entry is its own table body, like for expandable tables.
+    pub fn before_body(&self) -> Html {
+        self.spacer_body(self.before.clone(), self.window.before)
+    }
+
+    /// Render the spacer after the rows, as a table body of its own.
+    pub fn after_body(&self) -> Html {
+        self.spacer_body(self.after.clone(), self.window.after)
+    }
+
+    fn spacer_row(&self, r#ref: NodeRef, height: f64) -> Html {
+        if !self.is_enabled() {
+            return html!();
+        }
+
+        html!(
+            <tr ref={r#ref} aria-hidden="true" style={format!("height: {height}px;")}>
+                <td style="padding: 0; border: none;"></td>
+            </tr>
+        )
+    }
+
+    fn spacer_body(&self, r#ref: NodeRef, height: f64) -> Html {
+        if !self.is_enabled() {
+            return html!();
+        }
+
+        html!(
+            <tbody ref={r#ref} aria-hidden="true">
+                <tr style={format!("height: {height}px;")}>
+                    <td style="padding: 0; border: none;"></td>
+                </tr>
+            </tbody>
+        )
+    }
+}
+
+/// The offsets of the rows, followed by the total height.
+fn offsets<F>(count: usize, height: F) -> Vec<f64>
+where
+    F: Fn(usize) -> f64,
+{
+    let mut offset = 0.0;
+    let mut result = Vec::with_capacity(count + 1);
+    result.push(offset);
+    for index in 0..count {
+        offset += height(index);
+        result.push(offset);
+    }
+    result
+}
+
+#[derive(Default)]
+struct State {
+    scroll_top: f64,
+    viewport: f64,
+    /// measured heights, by row index
+    heights: HashMap<usize, f64>,
+    /// cached offsets of the rows, for the default height they were evaluated with
+    offsets: Option<(f64, Vec<f64>)>,
+}
+
+impl State {
+    fn evaluate(&mut self, count: usize, settings: &Option<TableVirtualization>) -> VirtualWindow {
+        let Some(settings) = settings else {
+            return VirtualWindow::all(count);
+        };
+
+        let default = settings.row_height.height();
+        let offsets = match self.offsets.take() {
+            Some((height, offsets)) if height == default && offsets.len() == count + 1 => offsets,
+            _ => offsets(count, |index| {
+                self.heights.get(&index).copied().unwrap_or(default)
+            }),
+        };
+
+        let window = VirtualWindow::from_offsets(
+            &offsets,
+            self.scroll_top,
+            self.viewport,
+            settings.overscan,
+        );
+        self.offsets = Some((default, offsets));
+        window
+    }
+
+    /// Measure the rendered rows, returns `true` if a height changed.
+    fn measure(&mut self, window: &VirtualWindow, before: &NodeRef, after: &NodeRef) -> bool {
+        let (Some(before), Some(after)) = (before.cast::<Element>(), after.cast::<Element>())
+        else {
+            return false;
+        };
+
+        let mut changed = false;
+        let mut index = window.start;
+        let mut current = before.next_element_sibling();
+
+        while let Some(element) = current {
+            if element == after || index >= window.end {
+                break;
+            }
+
+            let height = element.get_bounding_client_rect().height();
+            let previous = self.heights.insert(index, height);
+            if previous.map_or(true, |previous| (previous - height).abs() > 0.5) {
+                changed = true;
+            }
+
+            index += 1;
+            current = element.next_element_sibling();
+        }
+
+        if changed {
+            self.offsets = None;
+        }
+
+        changed
+    }
+}
+
+/// Track the rows of a table which need to be rendered.
+///
+/// If the settings are `None`, all rows will be rendered.
+///
+/// This hook is used by the [`super::Table`] when setting the `virtualization` property. Using it
+/// directly allows to virtualize a [`super::ComposableTable`]:
+///
+/// * Hand over the result to the `virtualization` property of the [`super::ComposableTable`].
+/// * Only render the rows of the current [`VirtualWindow`].
+/// * Render the spacers before and after the rows.
+#[hook]
+pub fn use_table_virtualization(
+    count: usize,
+    settings: Option<TableVirtualization>,
+) -> UseTableVirtualization {
+    let container = use_node_ref();
+    let before = use_node_ref();
+    let after = use_node_ref();
+
+    let state = use_mut_ref(State::default);
+    let window = use_state_eq(|| state.borrow_mut().evaluate(count, &settings));
+
+    // re-evaluate when the content or the settings change
+
+    {
+        let state = state.clone();
+        let window = window.setter();
+        let container = container.clone();
+        use_effect_with((count, settings.clone()), move |(count, settings)| {
+            let mut state = state.borrow_mut();
+            state.heights.retain(|index, _| index < count);
+            state.offsets = None;
+            if let Some(element) = container.cast::<Element>() {
+                state.viewport = element.client_height() as f64;
+            }
+            window.set(state.evaluate(*count, settings));
+        });
+    }
+
+    // measure rows with an estimated height
+
+    {
+        let state = state.clone();
+        let setter = window.setter();
+        let current = (*window).clone();
+        let settings = settings.clone();
+        let (before, after) = (before.clone(), after.clone());
+        use_effect(move || {
+            if let Some(TableVirtualization {
+                row_height: RowHeight::Estimated(_),
+                ..
+            }) = &settings
+            {
+                let mut state = state.borrow_mut();
+                if state.measure(&current, &before, &after) {
+                    setter.set(state.evaluate(count, &settings));
+                }
+            }
+        });
+    }
+
+    let onscroll = use_callback((count, settings.clone(), container.clone()), {
+        let window = window.setter();
+        move |_: Event, (count, settings, container)| {
+            if let Some(element) = container.cast::<Element>() {
+                let mut state = state.borrow_mut();
+                state.scroll_top = element.scroll_top() as f64;
+                state.viewport = element.client_height() as f64;
+                window.set(state.evaluate(*count, settings));
+            }
+        }
+    });
+
+    UseTableVirtualization {
+        window: match settings.is_some() {
+            true => (*window).clone(),
+            false => VirtualWindow::all(count),
+        },
+        settings,
+        container,
+        onscroll,
+        before,
+        after,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fixed() {
+        let window = VirtualWindow::evaluate(1000, |_| 10.0, 0.0, 100.0, 5);
+        assert_eq!(window.range(), 0..15);
+        assert_eq!(window.before, 0.0);
+        assert_eq!(window.after, 9850.0);
+
+        let window = VirtualWindow::evaluate(1000, |_| 10.0, 505.0, 100.0, 5);
+        assert_eq!(window.range(), 45..66);
+        assert_eq!(window.before, 450.0);
+        assert_eq!(window.after, 9340.0);
+    }
+
+    #[test]
+    fn test_end() {
+        let window = VirtualWindow::evaluate(100, |_| 10.0, 950.0, 100.0, 5);
+        assert_eq!(window.range(), 90..100);
+        assert_eq!(window.after, 0.0);
+
+        // scrolled beyond the end
+        let window = VirtualWindow::evaluate(100, |_| 10.0, 5000.0, 100.0, 2);
+        assert_eq!(window.range(), 97..100);
+        assert_eq!(window.before, 970.0);
+        assert_eq!(window.after, 0.0);
+    }
+
+    #[test]
+    fn test_varying() {
+        // every tenth row is expanded
+        let height = |index: usize| if index % 10 == 0 { 100.0 } else { 10.0 };
+
+        let window = VirtualWindow::evaluate(100, height, 0.0, 50.0, 0);
+        assert_eq!(window.range(), 0..1);
+
+        let window = VirtualWindow::evaluate(100, height, 100.0, 50.0, 1);
+        assert_eq!(window.range(), 0..7);
+        assert_eq!(window.before, 0.0);
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(offsets(3, |index| index as f64), vec![0.0, 0.0, 1.0, 3.0]);
+
+        let mut state = State {
+            viewport: 100.0,
+            ..Default::default()
+        };
+        let settings = Some(TableVirtualization::estimated(10.0).overscan(0));
+        assert_eq!(state.evaluate(100, &settings).after, 900.0);
+
+        // measured heights are applied, once the cache is dropped
+        state.heights.insert(50, 110.0);
+        assert_eq!(state.evaluate(100, &settings).after, 900.0);
+        state.offsets = None;
+        assert_eq!(state.evaluate(100, &settings).after, 1000.0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let window = VirtualWindow::evaluate(0, |_| 10.0, 0.0, 100.0, 5);
+        assert!(window.is_empty());
+        assert_eq!(window.before, 0.0);
+        assert_eq!(window.after, 0.0);
+    }
+}
@@ -134,10 +134,22 @@ pub struct ToolbarContentProperties {
 
     #[prop_or_default]
     pub id: AttrValue,
+
+    /// Render as container for the chips of active filters.
+    #[prop_or_default]
+    pub chip_container: bool,
 }
 
 #[function_component(ToolbarContent)]
 pub fn toolbar_content(props: &ToolbarContentProperties) -> Html {
+    if props.chip_container {
+        return html!(
+            <div class="pf-v5-c-toolbar__content pf-m-chip-container" id={&props.id}>
+                { for props.children.iter() }
+            </div>
+        );
+    }
+
     html! (
         <div class="pf-v5-c-toolbar__content" id={&props.id}>
             <div class="pf-v5-c-toolbar__content-section">
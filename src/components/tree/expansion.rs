@@ -0,0 +1,288 @@
+use super::{TreeNode, TreeTableModel};
+use std::collections::HashSet;
+use std::rc::Rc;
+use yew::{prelude::*, virtual_dom::Key};
+
+/// A set of expanded tree nodes, identified by their keys.
+///
+/// The helpers traversing the tree only work with the known children of a node. The children of
+/// lazy nodes (see [`TreeNode::is_lazy`]) are only known after they have been loaded, so they are
+/// not traversed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeExpansion {
+    expanded: HashSet<Key>,
+}
+
+impl From<HashSet<Key>> for TreeExpansion {
+    fn from(expanded: HashSet<Key>) -> Self {
+        Self { expanded }
+    }
+}
+
+impl From<TreeExpansion> for HashSet<Key> {
+    fn from(value: TreeExpansion) -> Self {
+        value.expanded
+    }
+}
+
+impl TreeExpansion {
+    pub fn is_expanded(&self, key: &Key) -> bool {
+        self.expanded.contains(key)
+    }
+
+    /// Iterate over the expanded keys, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Key> {
+        self.expanded.iter()
+    }
+
+    /// The expanded keys.
+    pub fn keys(&self) -> &HashSet<Key> {
+        &self.expanded
+    }
+
+    /// Expand or collapse a single node.
+    pub fn set(&mut self, key: Key, expanded: bool) {
+        match expanded {
+            true => self.expanded.insert(key),
+            false => self.expanded.remove(&key),
+        };
+    }
+
+    /// Expand all nodes having children.
+    pub fn expand_all<C>(&mut self, roots: &[Rc<dyn TreeNode<C>>]) {
+        for node in roots {
+            if node.is_lazy() {
+                continue;
+            }
+            let children = node.children();
+            if !children.is_empty() {
+                self.expanded.insert(node.key());
+                self.expand_all(&children);
+            }
+        }
+    }
+
+    /// Collapse all nodes.
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Expand all ancestors of a node, so that it becomes visible.
+    ///
+    /// Returns `false` if the node could not be found.
+    pub fn expand_to<C>(&mut self, roots: &[Rc<dyn TreeNode<C>>], key: &Key) -> bool {
+        match path(roots, key) {
+            Some(path) => {
+                self.expanded.extend(path);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Find the keys of the ancestors of a node, from the root down to its parent.
+fn path<C>(nodes: &[Rc<dyn TreeNode<C>>], key: &Key) -> Option<Vec<Key>> {
+    for node in nodes {
+        if &node.key() == key {
+            return Some(vec![]);
+        }
+        if node.is_lazy() {
+            continue;
+        }
+        if let Some(mut path) = path(&node.children(), key) {
+            path.insert(0, node.key());
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// The result of [`use_tree_expansion`].
+///
+/// The fields can be passed to the `expanded` and `onexpand` properties of the
+/// [`super::TreeTable`].
+#[derive(Clone, PartialEq)]
+pub struct UseTreeExpansion {
+    /// The current expansion
+    pub expanded: TreeExpansion,
+    /// Expand or collapse a node
+    pub onexpand: Callback<(Key, bool)>,
+    /// Expand all nodes
+    pub expand_all: Callback<()>,
+    /// Collapse all nodes
+    pub collapse_all: Callback<()>,
+    /// Expand all ancestors of a node, e.g. to reveal a search hit
+    pub expand_to: Callback<Key>,
+}
+
+/// A change of the expansion, applied to the latest state.
+type ExpansionUpdate = Box<dyn FnOnce(&mut TreeExpansion)>;
+
+#[derive(PartialEq)]
+struct ExpansionState(TreeExpansion);
+
+impl Reducible for ExpansionState {
+    type Action = ExpansionUpdate;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = self.0.clone();
+        action(&mut next);
+        match next == self.0 {
+            true => self,
+            false => Rc::new(Self(next)),
+        }
+    }
+}
+
+/// Control the expansion of the nodes of a [`super::TreeTable`].
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use yew::{prelude::*, virtual_dom::Key};
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column { Name };
+///
+/// struct Node {
+///   name: &'static str,
+///   children: Vec<Rc<dyn TreeNode<Column>>>,
+/// }
+///
+/// impl TreeNode<Column> for Node {
+///   fn key(&self) -> Key {
+///     self.name.into()
+///   }
+///
+///   fn render_cell(&self, _ctx: CellContext<'_, Column>) -> Cell {
+///     html!(self.name).into()
+///   }
+///
+///   fn children(&self) -> Vec<Rc<dyn TreeNode<Column>>> {
+///     self.children.clone()
+///   }
+/// }
+///
+/// #[derive(PartialEq)]
+/// struct Model;
+///
+/// impl TreeTableModel<Column> for Model {
+///   fn children(&self) -> Vec<Rc<dyn TreeNode<Column>>> {
+///     vec![Rc::new(Node { name: "parent", children: vec![
+///       Rc::new(Node { name: "child", children: vec![] }),
+///     ] })]
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let model = use_memo((), |()| Model);
+///   let expansion = use_tree_expansion(model.clone(), Default::default);
+///
+///   let header = html_nested! {
+///     <TreeTableHeader<Column>>
+///       <TableColumn<Column> label="Name" index={Column::Name} />
+///     </TreeTableHeader<Column>>
+///   };
+///
+///   html!(
+///     <>
+///       <Button onclick={expansion.expand_all.reform(|_| ())}>{ "Expand all" }</Button>
+///       <Button onclick={expansion.collapse_all.reform(|_| ())}>{ "Collapse all" }</Button>
+///       <Button onclick={expansion.expand_to.reform(|_| Key::from("child"))}>{ "Reveal child" }</Button>
+///       <TreeTable<Column, Model>
+///         {header}
+///         {model}
+///         expanded={expansion.expanded.clone()}
+///         onexpand={expansion.onexpand.clone()}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_tree_expansion<C, M, F>(model: Rc<M>, init: F) -> UseTreeExpansion
+where
+    C: 'static,
+    M: TreeTableModel<C> + PartialEq + 'static,
+    F: FnOnce() -> TreeExpansion,
+{
+    // using a reducer, as several nodes might get expanded at once
+    let state = use_reducer_eq(|| ExpansionState(init()));
+    let dispatcher = state.dispatcher();
+
+    let onexpand = use_callback(
+        dispatcher.clone(),
+        |(key, expanded): (Key, bool), dispatcher| {
+            dispatcher.dispatch(Box::new(move |state| state.set(key, expanded)));
+        },
+    );
+
+    let expand_all = use_callback(
+        (model.clone(), dispatcher.clone()),
+        |(), (model, dispatcher)| {
+            let roots = model.children();
+            dispatcher.dispatch(Box::new(move |state| state.expand_all(&roots)));
+        },
+    );
+
+    let collapse_all = use_callback(dispatcher.clone(), |(), dispatcher| {
+        dispatcher.dispatch(Box::new(|state| state.collapse_all()));
+    });
+
+    let expand_to = use_callback((model, dispatcher), |key: Key, (model, dispatcher)| {
+        let roots = model.children();
+        dispatcher.dispatch(Box::new(move |state| {
+            state.expand_to(&roots, &key);
+        }));
+    });
+
+    UseTreeExpansion {
+        expanded: state.0.clone(),
+        onexpand,
+        expand_all,
+        collapse_all,
+        expand_to,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::fixture::tree;
+    use super::*;
+
+    fn expanded(expansion: &TreeExpansion) -> Vec<String> {
+        let mut result = expansion
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+
+    #[test]
+    fn test_expand_all() {
+        let mut expansion = TreeExpansion::default();
+        expansion.expand_all(&tree());
+        assert_eq!(expanded(&expansion), vec!["a", "a1", "b"]);
+
+        expansion.collapse_all();
+        assert!(expanded(&expansion).is_empty());
+    }
+
+    #[test]
+    fn test_expand_to() {
+        let mut expansion = TreeExpansion::default();
+        assert!(expansion.expand_to(&tree(), &"a1x".into()));
+        assert_eq!(expanded(&expansion), vec!["a", "a1"]);
+
+        let mut expansion = TreeExpansion::default();
+        assert!(expansion.expand_to(&tree(), &"b".into()));
+        assert!(expanded(&expansion).is_empty());
+
+        assert!(!expansion.expand_to(&tree(), &"c".into()));
+    }
+}
@@ -0,0 +1,330 @@
+use super::{TreeChildrenFuture, TreeNode, TreeValue};
+use crate::prelude::{Cell, CellContext, Order, TableHeaderSortBy};
+use std::collections::HashSet;
+use std::rc::Rc;
+use yew::{virtual_dom::Key, Html};
+
+/// Filter the nodes of a [`super::TreeTable`], by searching for a term in the values of nodes.
+///
+/// A node matches if the text of one of its values (see [`TreeNode::value`]) contains the term,
+/// ignoring the case. Only the values of the filtered columns are searched, by default all
+/// columns of the table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeFilter<C> {
+    term: String,
+    columns: Vec<C>,
+}
+
+impl<C> TreeFilter<C>
+where
+    C: Clone + Eq,
+{
+    pub fn new(term: impl Into<String>) -> Self {
+        Self {
+            term: term.into().to_lowercase(),
+            columns: vec![],
+        }
+    }
+
+    /// Only search the values of the provided columns.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = C>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    pub fn term(&self) -> &str {
+        &self.term
+    }
+
+    /// An empty filter matches all nodes.
+    pub fn is_empty(&self) -> bool {
+        self.term.is_empty()
+    }
+
+    /// Check if the value of a column matches.
+    pub fn matches(&self, node: &dyn TreeNode<C>, column: &C) -> bool {
+        if !self.columns.is_empty() && !self.columns.contains(column) {
+            return false;
+        }
+
+        node.value(column)
+            .is_some_and(|value| value.to_string().to_lowercase().contains(&self.term))
+    }
+
+    /// Check if the value of any of the columns matches.
+    pub fn matches_any<'a>(
+        &self,
+        node: &dyn TreeNode<C>,
+        columns: impl IntoIterator<Item = &'a C>,
+    ) -> bool
+    where
+        C: 'a,
+    {
+        columns.into_iter().any(|column| self.matches(node, column))
+    }
+}
+
+/// A node, having its children replaced (e.g. filtered or sorted).
+pub(crate) struct FilteredNode<C> {
+    node: Rc<dyn TreeNode<C>>,
+    children: Vec<Rc<dyn TreeNode<C>>>,
+}
+
+impl<C> TreeNode<C> for FilteredNode<C> {
+    fn key(&self) -> Key {
+        self.node.key()
+    }
+
+    fn render_cell(&self, ctx: CellContext<'_, C>) -> Cell {
+        self.node.render_cell(ctx)
+    }
+
+    fn value(&self, column: &C) -> Option<TreeValue> {
+        self.node.value(column)
+    }
+
+    fn children(&self) -> Vec<Rc<dyn TreeNode<C>>> {
+        self.children.clone()
+    }
+
+    fn load_children(&self) -> TreeChildrenFuture<C>
+    where
+        C: 'static,
+    {
+        let children = self.children.clone();
+        Box::pin(async move { Ok(children) })
+    }
+
+    fn icon(&self, expanded: bool) -> Option<Html> {
+        self.node.icon(expanded)
+    }
+
+    fn badge(&self) -> Option<Html> {
+        self.node.badge()
+    }
+
+    fn actions(&self) -> Option<Html> {
+        self.node.actions()
+    }
+}
+
+/// Filter a tree, keeping the matching nodes along with their ancestors.
+///
+/// Matching nodes keep their whole subtree, while their ancestors only keep the children leading
+/// to a match. The children of lazy nodes are not known, so lazy nodes are only kept when
+/// matching themselves.
+///
+/// The keys of nodes having a match among their descendants get added to `revealed`.
+pub(crate) fn filter_nodes<C, F>(
+    nodes: &[Rc<dyn TreeNode<C>>],
+    f: &F,
+    revealed: &mut HashSet<Key>,
+) -> Vec<Rc<dyn TreeNode<C>>>
+where
+    C: 'static,
+    F: Fn(&Rc<dyn TreeNode<C>>) -> bool,
+{
+    nodes
+        .iter()
+        .filter_map(|node| {
+            if node.is_lazy() {
+                return f(node).then(|| node.clone());
+            }
+
+            let children = filter_nodes(&node.children(), f, revealed);
+            if !children.is_empty() {
+                revealed.insert(node.key());
+            }
+
+            if f(node) {
+                // keep the whole subtree of a match
+                Some(node.clone())
+            } else if children.is_empty() {
+                None
+            } else {
+                Some(Rc::new(FilteredNode {
+                    node: node.clone(),
+                    children,
+                }) as Rc<dyn TreeNode<C>>)
+            }
+        })
+        .collect()
+}
+
+/// Sort siblings by the value of a column, keeping the order of equal siblings.
+///
+/// The children of lazy nodes are not known, so they are not sorted.
+pub(crate) fn sort_nodes<C>(
+    nodes: &[Rc<dyn TreeNode<C>>],
+    sortby: &TableHeaderSortBy<C>,
+) -> Vec<Rc<dyn TreeNode<C>>>
+where
+    C: Clone + Eq + 'static,
+{
+    let mut nodes = nodes
+        .iter()
+        .map(|node| {
+            if node.is_lazy() {
+                return node.clone();
+            }
+
+            let children = node.children();
+            match children.is_empty() {
+                true => node.clone(),
+                false => Rc::new(FilteredNode {
+                    node: node.clone(),
+                    children: sort_nodes(&children, sortby),
+                }) as Rc<dyn TreeNode<C>>,
+            }
+        })
+        .map(|node| (node.value(&sortby.index), node))
+        .collect::<Vec<_>>();
+
+    nodes.sort_by(|(a, _), (b, _)| {
+        let order = match (a, b) {
+            (Some(a), Some(b)) => a.compare(b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        };
+        match sortby.order {
+            Order::Ascending => order,
+            Order::Descending => order.reverse(),
+        }
+    });
+
+    nodes.into_iter().map(|(_, node)| node).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::fixture::{node, tree};
+    use super::*;
+    use yew::html;
+
+    /// Render the tree as string, e.g. `a[a1[a1x]]`.
+    fn dump(nodes: &[Rc<dyn TreeNode<()>>]) -> String {
+        nodes
+            .iter()
+            .map(|node| {
+                let children = node.children();
+                match children.is_empty() {
+                    true => node.key().to_string(),
+                    false => format!("{}[{}]", node.key(), dump(&children)),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn filter(query: &str) -> String {
+        let filter = TreeFilter::new(query);
+        dump(&filter_nodes(
+            &tree(),
+            &|node: &Rc<dyn TreeNode<()>>| filter.matches(node.as_ref(), &()),
+            &mut HashSet::new(),
+        ))
+    }
+
+    #[test]
+    fn test_keep_ancestors() {
+        assert_eq!(filter("a1x"), "a[a1[a1x]]");
+        assert_eq!(filter("x"), "a[a1[a1x]],b[bx]");
+    }
+
+    #[test]
+    fn test_only_matching_children() {
+        assert_eq!(filter("a"), "a[a1[a1x,a1y],a2]");
+        assert_eq!(filter("b"), "b[bx]");
+        assert_eq!(filter("a2"), "a[a2]");
+    }
+
+    #[test]
+    fn test_keep_subtree() {
+        let tree = vec![node(
+            "root",
+            vec![
+                node("match", vec![node("x", vec![]), node("y", vec![])]),
+                node("other", vec![]),
+            ],
+        )];
+        let filter = TreeFilter::new("match");
+        let mut revealed = HashSet::new();
+        let nodes = filter_nodes(
+            &tree,
+            &|node: &Rc<dyn TreeNode<()>>| filter.matches(node.as_ref(), &()),
+            &mut revealed,
+        );
+        assert_eq!(dump(&nodes), "root[match[x,y]]");
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert_eq!(filter("z"), "");
+        assert_eq!(filter(""), "a[a1[a1x,a1y],a2],b[bx]");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(filter("A1X"), "a[a1[a1x]]");
+    }
+
+    #[test]
+    fn test_revealed() {
+        let filter = TreeFilter::new("x");
+        let mut revealed = HashSet::new();
+        filter_nodes(
+            &tree(),
+            &|node: &Rc<dyn TreeNode<()>>| filter.matches(node.as_ref(), &()),
+            &mut revealed,
+        );
+
+        let mut revealed = revealed
+            .into_iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>();
+        revealed.sort();
+        assert_eq!(revealed, vec!["a", "a1", "b"]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let sorted = |order| {
+            dump(&sort_nodes(
+                &tree(),
+                &TableHeaderSortBy { index: (), order },
+            ))
+        };
+        assert_eq!(sorted(Order::Ascending), "a[a1[a1x,a1y],a2],b[bx]");
+        assert_eq!(sorted(Order::Descending), "b[bx],a[a2,a1[a1y,a1x]]");
+    }
+
+    #[test]
+    fn test_sort_numbers() {
+        struct Sized(&'static str, Option<f64>);
+
+        impl TreeNode<()> for Sized {
+            fn key(&self) -> Key {
+                self.0.into()
+            }
+
+            fn render_cell(&self, _ctx: CellContext<'_, ()>) -> Cell {
+                html!().into()
+            }
+
+            fn children(&self) -> Vec<Rc<dyn TreeNode<()>>> {
+                vec![]
+            }
+
+            fn value(&self, _column: &()) -> Option<TreeValue> {
+                self.1.map(TreeValue::Number)
+            }
+        }
+
+        let nodes: Vec<Rc<dyn TreeNode<()>>> = vec![
+            Rc::new(Sized("ten", Some(10.0))),
+            Rc::new(Sized("none", None)),
+            Rc::new(Sized("two", Some(2.0))),
+        ];
+        let sorted = dump(&sort_nodes(&nodes, &TableHeaderSortBy::ascending(())));
+        assert_eq!(sorted, "none,two,ten");
+    }
+}
@@ -0,0 +1,65 @@
+//! Nodes for testing the tree components
+
+use super::{TreeNode, TreeValue};
+use crate::prelude::{Cell, CellContext};
+use std::rc::Rc;
+use yew::{html, virtual_dom::Key};
+
+pub(super) struct Node {
+    key: &'static str,
+    children: Vec<Rc<dyn TreeNode<()>>>,
+    lazy: bool,
+}
+
+impl TreeNode<()> for Node {
+    fn key(&self) -> Key {
+        self.key.into()
+    }
+
+    fn render_cell(&self, _ctx: CellContext<'_, ()>) -> Cell {
+        html!(self.key).into()
+    }
+
+    fn children(&self) -> Vec<Rc<dyn TreeNode<()>>> {
+        self.children.clone()
+    }
+
+    fn value(&self, _column: &()) -> Option<TreeValue> {
+        Some(self.key.into())
+    }
+
+    fn is_lazy(&self) -> bool {
+        self.lazy
+    }
+}
+
+pub(super) fn node(key: &'static str, children: Vec<Rc<dyn TreeNode<()>>>) -> Rc<dyn TreeNode<()>> {
+    Rc::new(Node {
+        key,
+        children,
+        lazy: false,
+    })
+}
+
+/// A node loading its children on demand.
+pub(super) fn lazy(key: &'static str) -> Rc<dyn TreeNode<()>> {
+    Rc::new(Node {
+        key,
+        children: vec![],
+        lazy: true,
+    })
+}
+
+/// a: [ a1: [ a1x, a1y ], a2 ], b: [ bx ]
+pub(super) fn tree() -> Vec<Rc<dyn TreeNode<()>>> {
+    vec![
+        node(
+            "a",
+            vec![
+                node("a1", vec![node("a1x", vec![]), node("a1y", vec![])]),
+                node("a2", vec![]),
+            ],
+        ),
+        node("b", vec![node("bx", vec![])]),
+    ]
+}
@@ -27,6 +27,7 @@ where
 
             <tr class="pf-v5-c-table__tr">
 
+                // the sort state is provided by the table
                 { for props.children.iter().enumerate().map(|(n,mut c)| {
                     if n == 0 {
                         let props = Rc::make_mut(&mut c.props);
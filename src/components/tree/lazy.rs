@@ -0,0 +1,130 @@
+use super::TreeNode;
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::{prelude::*, virtual_dom::Key};
+
+#[derive(Default)]
+struct Invalidations {
+    /// Incremented by every invalidation
+    version: usize,
+    /// The version of the last invalidation of all nodes
+    all: usize,
+    /// The version of the last invalidation, by node key
+    nodes: Vec<(Key, usize)>,
+}
+
+/// Invalidate the children of nodes of a [`super::TreeTable`].
+///
+/// Invalidating a node drops its loaded children. If the node is expanded, its children are
+/// loaded (or, for non-lazy nodes, requested) again, refreshing the subtree.
+///
+/// Created using [`use_tree_invalidator`] and handed over to the `invalidator` property of the
+/// [`super::TreeTable`].
+#[derive(Clone)]
+pub struct TreeInvalidator {
+    state: Rc<RefCell<Invalidations>>,
+    /// The version at the time of rendering
+    version: usize,
+    trigger: UseForceUpdateHandle,
+}
+
+impl PartialEq for TreeInvalidator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state) && self.version == other.version
+    }
+}
+
+impl TreeInvalidator {
+    /// Invalidate a node, identified by its [`TreeNode::key`], refreshing its subtree.
+    pub fn invalidate<C>(&self, node: &Rc<dyn TreeNode<C>>) {
+        {
+            let mut state = self.state.borrow_mut();
+            state.version += 1;
+            let version = state.version;
+            let key = node.key();
+            state.nodes.retain(|(node, _)| *node != key);
+            state.nodes.push((key, version));
+        }
+        self.trigger.force_update();
+    }
+
+    /// Invalidate all nodes, refreshing the whole tree.
+    pub fn invalidate_all(&self) {
+        {
+            let mut state = self.state.borrow_mut();
+            state.version += 1;
+            state.all = state.version;
+            // superseded by invalidating everything
+            state.nodes.clear();
+        }
+        self.trigger.force_update();
+    }
+
+    /// The current version, recorded when loading children.
+    pub(crate) fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Check if a node was invalidated after its children were loaded at a version.
+    pub(crate) fn is_invalidated(&self, key: &Key, loaded: usize) -> bool {
+        let state = self.state.borrow();
+        state.all > loaded
+            || state
+                .nodes
+                .iter()
+                .any(|(node, version)| node == key && *version > loaded)
+    }
+}
+
+/// Create a [`TreeInvalidator`].
+#[hook]
+pub fn use_tree_invalidator() -> TreeInvalidator {
+    let state = use_mut_ref(Invalidations::default);
+    let trigger = use_force_update();
+
+    let version = state.borrow().version;
+
+    TreeInvalidator {
+        state,
+        version,
+        trigger,
+    }
+}
+
+/// The children of a lazy node.
+pub(crate) enum LazyChildren<C> {
+    NotLoaded,
+    Loading,
+    Loaded(Vec<Rc<dyn TreeNode<C>>>),
+    Failed(String),
+}
+
+impl<C> Clone for LazyChildren<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::NotLoaded => Self::NotLoaded,
+            Self::Loading => Self::Loading,
+            Self::Loaded(children) => Self::Loaded(children.clone()),
+            Self::Failed(err) => Self::Failed(err.clone()),
+        }
+    }
+}
+
+/// The state of loading the children of a lazy node.
+pub(crate) struct LazyState<C> {
+    /// The key of the node the children belong to
+    pub(crate) node: Key,
+    /// The version of the invalidator when loading started
+    pub(crate) version: usize,
+    pub(crate) children: LazyChildren<C>,
+}
+
+impl<C> Clone for LazyState<C> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node.clone(),
+            version: self.version,
+            children: self.children.clone(),
+        }
+    }
+}
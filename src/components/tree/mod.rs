@@ -1,16 +1,44 @@
-//! Tree table
+//! Tree table and tree view
 //!
 //! **NOTE:** This is in an experimental state.
 
+mod expansion;
+mod filter;
+#[cfg(test)]
+mod fixture;
 mod header;
+mod lazy;
 mod model;
+mod navigation;
+mod reorder;
+mod selection;
+mod view;
 
+pub use expansion::*;
+pub use filter::*;
 pub use header::*;
+pub use lazy::*;
 pub use model::*;
+pub use reorder::*;
+pub use selection::*;
+pub use view::*;
 
-use crate::prelude::{use_random_id, AsClasses, CellContext, ExtendClasses};
+use crate::components::table::{DragHandle, DragHandleProperties, RowSelectEvent, TableCheck};
+use crate::prelude::{
+    use_random_id, AsClasses, Button, ButtonVariant, CellContext, CheckboxState, ExtendClasses,
+    Icon, Spinner, SpinnerSize, TableHeaderContext, TableHeaderSortBy,
+};
+use filter::{filter_nodes, sort_nodes};
+use navigation::RowNavigation;
+use reorder::{keyboard_target, use_node_reorder, DropPosition, NodePath, NodeReorder};
+use std::collections::HashSet;
 use std::rc::Rc;
-use yew::{prelude::*, virtual_dom::VChild};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::{
+    prelude::*,
+    virtual_dom::{Key, VChild},
+};
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum TreeTableMode {
@@ -50,8 +78,73 @@ where
 
     pub model: Rc<M>,
 
+    /// The initial expansion of the nodes, unless the expansion is controlled.
     #[prop_or(true)]
     pub default_expansion: bool,
+
+    /// The expanded nodes, controlling the expansion.
+    ///
+    /// If not set, each row keeps track of its expansion itself, starting with the
+    /// `default_expansion`. Also see [`use_tree_expansion`].
+    #[prop_or_default]
+    pub expanded: Option<TreeExpansion>,
+
+    /// Called when the user expands or collapses a node.
+    ///
+    /// While filtering, this is also called for revealing the matches, possibly for several
+    /// nodes at once.
+    #[prop_or_default]
+    pub onexpand: Callback<(Key, bool)>,
+
+    /// Sort the siblings of the tree by the value of a column, see [`TreeNode::value`].
+    ///
+    /// This is also shown by the sortable columns of the header.
+    #[prop_or_default]
+    pub sortby: Option<TableHeaderSortBy<C>>,
+
+    /// Called when the user clicks a sortable column of the header.
+    #[prop_or_default]
+    pub onsort: Callback<TableHeaderSortBy<C>>,
+
+    /// Filter the tree, keeping matching nodes along with their subtree and their ancestors.
+    ///
+    /// Matching cells get the `pf-m-filter-match` class, for highlighting them, and the
+    /// ancestors of matches get expanded. The content of the cells is left unchanged.
+    #[prop_or_default]
+    pub filter: Option<TreeFilter<C>>,
+
+    /// Allows invalidating nodes, refreshing their children.
+    #[prop_or_default]
+    pub invalidator: Option<TreeInvalidator>,
+
+    /// Render a selection checkbox for each node.
+    ///
+    /// Selecting a node selects all of its descendants, also see [`TreeSelection`].
+    #[prop_or_default]
+    pub selectable: bool,
+
+    /// The selected nodes, controlling the selection.
+    ///
+    /// If not set, the table keeps track of the selection itself.
+    #[prop_or_default]
+    pub selected: Option<TreeSelection>,
+
+    /// Called with the new selection, when the user changes the selection.
+    #[prop_or_default]
+    pub onselect: Callback<TreeSelection>,
+
+    /// Allow moving nodes, using drag and drop, or the keyboard.
+    ///
+    /// Renders a drag handle per row. Dropping a node before or after a row makes it a sibling of
+    /// that row, dropping it onto a row makes it the first child. Using the keyboard, a grabbed
+    /// node moves among its siblings using the up and down keys, after its parent using the left
+    /// key, and into its previous sibling using the right key. Nodes are grabbed using the space
+    /// key on the drag handle, or Ctrl+Space on the row.
+    ///
+    /// The index of the [`TreeReorder`] is based on the nodes as shown, which is different from
+    /// the model while sorting or filtering.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<TreeReorder>>,
 }
 
 #[function_component(TreeTable)]
@@ -64,17 +157,71 @@ where
 
     class.extend_from(&props.mode);
 
-    let headers = use_memo(props.header.props.clone(), |header| {
-        collect_columns(&header)
-    });
+    let headers = use_memo(props.header.props.clone(), |header| collect_columns(header));
+
+    let selection = use_node_selection(
+        props.model.clone(),
+        props.selectable,
+        props.selected.clone(),
+        props.onselect.clone(),
+    );
+
+    let reorder = use_node_reorder(props.onreorder.clone());
+
+    // the row which can be reached using the tab key, defaults to the first one
+    let focused = use_state_eq(|| None::<Key>);
+    let onfocus = use_callback(focused.clone(), |key: Key, focused| focused.set(Some(key)));
+
+    let expanded = use_memo(props.expanded.clone(), |expanded| expanded.clone());
 
-    let content = use_memo(
-        (props.model.clone(), headers, props.default_expansion),
-        |(model, headers, default_expansion)| {
-            render_model(model, headers.clone(), *default_expansion)
+    let filter = props.filter.clone().filter(|filter| !filter.is_empty());
+    let nodes = use_memo(
+        (
+            props.model.clone(),
+            headers.clone(),
+            props.sortby.clone(),
+            filter.clone(),
+        ),
+        |(model, headers, sortby, filter)| {
+            let mut nodes = model.children();
+            let mut revealed = HashSet::new();
+            if let Some(filter) = filter {
+                nodes = filter_nodes(
+                    &nodes,
+                    &|node| filter.matches_any(node.as_ref(), headers.iter().map(|c| &c.index)),
+                    &mut revealed,
+                );
+            }
+            if let Some(sortby) = sortby {
+                nodes = sort_nodes(&nodes, sortby);
+            }
+            (nodes, Rc::new(revealed))
         },
     );
 
+    let context = Rc::new(RowContext {
+        headers,
+        default_expansion: props.default_expansion,
+        expanded,
+        onexpand: props.onexpand.clone(),
+        sortby: props.sortby.clone(),
+        filter,
+        revealed: nodes.1.clone(),
+        invalidator: props.invalidator.clone(),
+        selection,
+        reorder,
+        focused: (*focused).clone(),
+        onfocus,
+    });
+
+    let content = render_nodes(None, nodes.0.clone(), Visibility::new(), context);
+
+    // sortable columns of the header show and change the sort state of the table
+    let header_context = TableHeaderContext {
+        sortby: props.sortby.clone(),
+        onsort: props.onsort.clone(),
+    };
+
     html!(
         <table
             id={&props.id}
@@ -82,10 +229,12 @@ where
             role="treegrid"
         >
 
-            { props.header.clone() }
+            <ContextProvider<TableHeaderContext<C>> context={header_context}>
+                { props.header.clone() }
+            </ContextProvider<TableHeaderContext<C>>>
 
             <tbody class="pf-v5-c-table__tbody">
-                { (*content).clone() }
+                { content }
             </tbody>
 
         </table>
@@ -136,26 +285,51 @@ impl Visibility {
     }
 }
 
-fn render_model<C, M>(model: &Rc<M>, headers: Rc<Vec<Column<C>>>, default_expansion: bool) -> Html
+/// Information shared by all rows.
+struct RowContext<C>
 where
     C: Clone + Eq + 'static,
-    M: TreeTableModel<C>,
 {
-    render_nodes(
-        1,
-        model.children(),
-        Visibility::new(),
-        headers,
-        default_expansion,
-    )
+    headers: Rc<Vec<Column<C>>>,
+    default_expansion: bool,
+    expanded: Rc<Option<TreeExpansion>>,
+    onexpand: Callback<(Key, bool)>,
+    sortby: Option<TableHeaderSortBy<C>>,
+    filter: Option<TreeFilter<C>>,
+    /// The nodes having a match among their descendants
+    revealed: Rc<HashSet<Key>>,
+    invalidator: Option<TreeInvalidator>,
+    selection: Option<NodeSelection<C>>,
+    reorder: Option<NodeReorder>,
+    focused: Option<Key>,
+    onfocus: Callback<Key>,
+}
+
+impl<C> PartialEq for RowContext<C>
+where
+    C: Clone + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.headers == other.headers
+            && self.default_expansion == other.default_expansion
+            && Rc::ptr_eq(&self.expanded, &other.expanded)
+            && self.onexpand == other.onexpand
+            && self.sortby == other.sortby
+            && self.filter == other.filter
+            && Rc::ptr_eq(&self.revealed, &other.revealed)
+            && self.invalidator == other.invalidator
+            && self.selection == other.selection
+            && self.reorder == other.reorder
+            && self.focused == other.focused
+            && self.onfocus == other.onfocus
+    }
 }
 
 fn render_nodes<C>(
-    level: usize,
+    parent: Option<&NodePath>,
     nodes: Vec<Rc<dyn TreeNode<C>>>,
     visibility: Visibility,
-    headers: Rc<Vec<Column<C>>>,
-    default_expansion: bool,
+    context: Rc<RowContext<C>>,
 ) -> Html
 where
     C: Clone + Eq + 'static,
@@ -165,9 +339,27 @@ where
         {
             for nodes.iter()
                 .enumerate()
-                .map(|(position,node) | html!(
-                    <Row<C> {visibility} {size} {position} {level} node={node.clone()} headers={headers.clone()} {default_expansion}/>
-                ))
+                .map(|(position,node) | {
+                    let path = match parent {
+                        Some(parent) => parent.child(node.key(), position),
+                        None => NodePath::root(node.key(), position),
+                    };
+                    // moving a node into its previous sibling appends it to the known children
+                    let previous = position
+                        .checked_sub(1)
+                        .filter(|_| context.reorder.is_some())
+                        .map(|previous| {
+                            let previous = &nodes[previous];
+                            let children = match previous.is_lazy() {
+                                true => 0,
+                                false => previous.children().len(),
+                            };
+                            (previous.key(), children)
+                        });
+                    html!(
+                        <Row<C> key={node.key()} {visibility} {size} {position} {path} {previous} node={node.clone()} context={context.clone()}/>
+                    )
+                })
         }
     )
 }
@@ -177,13 +369,14 @@ struct RowProperties<C>
 where
     C: Clone + Eq + 'static,
 {
-    level: usize,
     size: usize,
     position: usize,
+    path: NodePath,
+    /// The key and the number of children of the previous sibling, when reordering
+    previous: Option<(Key, usize)>,
     node: Rc<dyn TreeNode<C>>,
     visibility: Visibility,
-    headers: Rc<Vec<Column<C>>>,
-    default_expansion: bool,
+    context: Rc<RowContext<C>>,
 }
 
 impl<C> PartialEq for RowProperties<C>
@@ -192,11 +385,12 @@ where
 {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.node, &other.node)
-            && self.level == other.level
             && self.size == other.size
             && self.position == other.position
+            && self.path == other.path
+            && self.previous == other.previous
             && self.visibility == other.visibility
-            && self.headers == other.headers
+            && self.context == other.context
     }
 }
 
@@ -205,30 +399,276 @@ fn row<C>(props: &RowProperties<C>) -> Html
 where
     C: Clone + Eq + 'static,
 {
-    let expanded = use_state_eq(|| props.default_expansion);
+    let lazy = props.node.is_lazy();
+    let key = props.node.key();
+
+    // lazy nodes only load their children when being expanded
+    let local = use_state_eq(|| props.context.default_expansion && !lazy);
+    let expanded = match &*props.context.expanded {
+        Some(expansion) => expansion.is_expanded(&key),
+        None => *local,
+    };
+    let onexpand = {
+        let (controlled, key) = (props.context.expanded.is_some(), key.clone());
+        props.context.onexpand.reform(move |expanded: bool| {
+            if !controlled {
+                local.set(expanded);
+            }
+            (key.clone(), expanded)
+        })
+    };
+
+    // reveal matches of the filter
+    {
+        let revealed = props.context.revealed.contains(&key);
+        let onexpand = onexpand.clone();
+        use_effect_with(
+            (revealed, props.context.filter.clone()),
+            move |(revealed, _)| {
+                if *revealed && !expanded {
+                    onexpand.emit(true);
+                }
+            },
+        );
+    }
+
+    // expand the new parent of a moved node
+    {
+        let reveal = props
+            .context
+            .reorder
+            .as_ref()
+            .and_then(|reorder| reorder.reveal.as_ref())
+            .filter(|(parent, _)| *parent == key)
+            .map(|(_, count)| *count);
+        let onexpand = onexpand.clone();
+        use_effect_with(reveal, move |reveal| {
+            if reveal.is_some() && !expanded {
+                onexpand.emit(true);
+            }
+        });
+    }
 
     let mut class = classes!("pf-v5-c-table__tr");
 
-    if *expanded {
+    if expanded {
         class.extend(classes!("pf-m-expanded"));
     }
 
-    let children = props.node.children();
+    let drag = props
+        .context
+        .reorder
+        .as_ref()
+        .map(|reorder| row_drag(reorder, &props.path, props.size, &props.previous));
+    if drag.as_ref().is_some_and(|drag| drag.dragged) {
+        class.push(classes!("pf-m-ghost-row"));
+    }
+
+    let id = props.node.key();
+    let version = props
+        .context
+        .invalidator
+        .as_ref()
+        .map(|i| i.version())
+        .unwrap_or_default();
+    let state = use_state(|| LazyState {
+        node: id.clone(),
+        version,
+        children: LazyChildren::NotLoaded,
+    });
+    // the id of the latest load, to discard results of stale ones
+    let latest = use_mut_ref(|| 0usize);
+
+    // loaded children of a different node, or invalidated ones, need to be loaded again
+    let loaded = match state.node == id
+        && !props
+            .context
+            .invalidator
+            .as_ref()
+            .is_some_and(|i| i.is_invalidated(&id, state.version))
+    {
+        true => state.children.clone(),
+        false => LazyChildren::NotLoaded,
+    };
+
+    {
+        let load = lazy && expanded && matches!(loaded, LazyChildren::NotLoaded);
+        let (node, state) = (props.node.clone(), state.clone());
+        let onloaded = props
+            .context
+            .selection
+            .as_ref()
+            .map(|selection| selection.onloaded.clone());
+        use_effect_with((load, id.clone(), version), move |(load, id, version)| {
+            if !*load {
+                return;
+            }
+
+            let request = {
+                let mut latest = latest.borrow_mut();
+                *latest += 1;
+                *latest
+            };
+
+            let (id, version) = (id.clone(), *version);
+            state.set(LazyState {
+                node: id.clone(),
+                version,
+                children: LazyChildren::Loading,
+            });
+            let future = node.load_children();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = future.await;
+                if *latest.borrow() != request {
+                    // a newer load is pending
+                    return;
+                }
+
+                if let (Ok(children), Some(onloaded)) = (&result, &onloaded) {
+                    onloaded.emit((node.clone(), children.clone()));
+                }
+
+                state.set(LazyState {
+                    node: id,
+                    version,
+                    children: match result {
+                        Ok(children) => LazyChildren::Loaded(children),
+                        Err(err) => LazyChildren::Failed(err),
+                    },
+                });
+            });
+        });
+    }
+
+    let select = props.context.selection.as_ref().map(|selection| {
+        let node = props.node.clone();
+        (
+            selection.state(&props.node.key()),
+            selection
+                .onselect
+                .reform(move |selected| (node.clone(), selected)),
+        )
+    });
+
+    let children = match (lazy, &loaded) {
+        (false, _) => props.node.children(),
+        (true, LazyChildren::Loaded(children)) => match &props.context.sortby {
+            Some(sortby) => sort_nodes(children, sortby),
+            None => children.clone(),
+        },
+        (true, _) => vec![],
+    };
+    // until loaded, lazy nodes are assumed to have children
+    let has_children = match (lazy, &loaded) {
+        (true, LazyChildren::Loaded(_)) | (false, _) => !children.is_empty(),
+        (true, _) => true,
+    };
+
+    // roving tabindex, only a single row can be reached using the tab key
+    let tabindex = match &props.context.focused {
+        Some(focused) => *focused == key,
+        None => props.path.level() == 1 && props.position == 0,
+    };
+    let onfocus = {
+        let key = key.clone();
+        props
+            .context
+            .onfocus
+            .reform(move |_: FocusEvent| key.clone())
+    };
+
+    let onkeydown = {
+        let onexpand = onexpand.clone();
+        let key = key.clone();
+        let ongrab = props
+            .context
+            .reorder
+            .as_ref()
+            .map(|reorder| reorder.ongrab.clone());
+        Callback::from(move |evt: KeyboardEvent| {
+            // only handle events of the row itself, not of its content (e.g. a checkbox)
+            if evt.target() != evt.current_target() {
+                return;
+            }
+            let Some(row) = evt
+                .current_target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            else {
+                return;
+            };
+
+            // grab the node, the drag handle takes over the keyboard until the node is released
+            if let Some(ongrab) = ongrab
+                .as_ref()
+                .filter(|_| evt.ctrl_key() && evt.key() == " ")
+            {
+                evt.prevent_default();
+                ongrab.emit(Some(key.clone()));
+                if let Some(handle) = row
+                    .query_selector(".pf-v5-c-table__draggable button")
+                    .ok()
+                    .flatten()
+                    .and_then(|handle| handle.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = handle.focus();
+                }
+                return;
+            }
+
+            let navigation = match evt.key().as_str() {
+                "ArrowDown" => Some(RowNavigation::Next),
+                "ArrowUp" => Some(RowNavigation::Previous),
+                "Home" => Some(RowNavigation::First),
+                "End" => Some(RowNavigation::Last),
+                "ArrowRight" if has_children && !expanded => {
+                    onexpand.emit(true);
+                    None
+                }
+                "ArrowRight" => Some(RowNavigation::FirstChild),
+                "ArrowLeft" if has_children && expanded => {
+                    onexpand.emit(false);
+                    None
+                }
+                "ArrowLeft" => Some(RowNavigation::Parent),
+                "Enter" | " " => {
+                    if has_children {
+                        onexpand.emit(!expanded);
+                    }
+                    None
+                }
+                _ => return,
+            };
+
+            evt.prevent_default();
+            if let Some(navigation) = navigation {
+                navigation.focus(&row);
+            }
+        })
+    };
 
     html!(
         <>
             <tr
                 {class}
                 role="row"
-                tabindex="0"
-                aria-level={ props.level.to_string() }
-                aria-expanded={ (*expanded).to_string() }
+                tabindex={ if tabindex { "0" } else { "-1" } }
+                aria-level={ props.path.level().to_string() }
+                aria-expanded={ has_children.then(|| expanded.to_string()) }
                 aria-setsize={ props.size.to_string() }
-                aria-posinset={ props.position.to_string() }
+                aria-posinset={ (props.position + 1).to_string() }
                 hidden={!props.visibility.is_visible()}
+                {onfocus}
+                {onkeydown}
+                draggable={drag.as_ref().map(|_| "true")}
+                style={drag.as_ref().and_then(|drag| drag.style)}
+                ondragstart={drag.as_ref().map(|drag| drag.ondragstart.clone())}
+                ondragover={drag.as_ref().map(|drag| drag.ondragover.clone())}
+                ondrop={drag.as_ref().map(|drag| drag.ondrop.clone())}
+                ondragend={drag.as_ref().map(|drag| drag.ondragend.clone())}
             >
 
-                { for props.headers.iter().enumerate().map(|(nr, column)| {
+                { for props.context.headers.iter().enumerate().map(|(nr, column)| {
 
                     let cell = props.node.render_cell(CellContext{column: &column.index});
                     let mut class = match cell.center {
@@ -236,19 +676,18 @@ where
                         false => Classes::new(),
                     };
 
+                    if props.context.filter.as_ref().is_some_and(|filter| filter.matches(props.node.as_ref(), &column.index)) {
+                        class.push("pf-m-filter-match");
+                    }
+
                     match nr {
                         0 => {
-                             let ontoggle = {
-                                let expanded = expanded.clone();
-                                Callback::from(move |_| {
-                                    expanded.set(!*expanded);
-                                })
-                            };
+                            let ontoggle = onexpand.reform(move |()| !expanded);
 
                             class.push(classes!("pf-v5-c-table__th", "pf-v5-c-table__tree-view-title-cell"));
                             html!(
                                 <th {class}>
-                                    <MainCell has_children={!children.is_empty()} {ontoggle} expanded={*expanded}>
+                                    <MainCell {has_children} {ontoggle} {expanded} select={select.clone()} drag={drag.as_ref().map(|drag| drag.handle.clone())}>
                                         { cell.content }
                                     </MainCell>
                                 </th>
@@ -268,13 +707,241 @@ where
                 // cell for the actions
                 <td></td>
             </tr>
-            if props.visibility.nested(*expanded).is_visible() {
-                { render_nodes(props.level + 1, children, props.visibility.nested(*expanded), props.headers.clone(), props.default_expansion) }
+            if props.visibility.nested(expanded).is_visible() {
+                {
+                    match loaded {
+                        LazyChildren::Loading if lazy => render_status(
+                            props.path.level() + 1,
+                            props.context.headers.len(),
+                            html!(<Spinner size={SpinnerSize::Md} />),
+                        ),
+                        LazyChildren::Failed(err) if lazy => {
+                            let onretry = {
+                                let (state, id) = (state.clone(), id.clone());
+                                Callback::from(move |_| {
+                                    state.set(LazyState {
+                                        node: id.clone(),
+                                        version,
+                                        children: LazyChildren::NotLoaded,
+                                    });
+                                })
+                            };
+                            render_status(
+                                props.path.level() + 1,
+                                props.context.headers.len(),
+                                html!(
+                                    <>
+                                        { Icon::ExclamationCircle.with_classes(classes!("pf-v5-u-danger-color-100", "pf-v5-u-mr-sm")) }
+                                        { err }
+                                        <Button variant={ButtonVariant::InlineLink} class="pf-v5-u-ml-sm" onclick={onretry}>{ "Retry" }</Button>
+                                    </>
+                                ),
+                            )
+                        }
+                        _ => render_nodes(
+                            Some(&props.path),
+                            children,
+                            props.visibility.nested(expanded),
+                            props.context.clone(),
+                        ),
+                    }
+                }
             }
         </>
     )
 }
 
+/// Everything required to drag a row, or to drop a node onto it.
+struct RowDrag {
+    /// The row is being dragged
+    dragged: bool,
+    /// Indicates dropping a node onto the row
+    style: Option<&'static str>,
+    ondragstart: Callback<DragEvent>,
+    ondragover: Callback<DragEvent>,
+    ondrop: Callback<DragEvent>,
+    ondragend: Callback<DragEvent>,
+    handle: DragHandleProperties,
+}
+
+fn row_drag(
+    reorder: &NodeReorder,
+    path: &NodePath,
+    size: usize,
+    previous: &Option<(Key, usize)>,
+) -> RowDrag {
+    let key = path.key().clone();
+    let dragged = reorder
+        .dragging
+        .as_ref()
+        .is_some_and(|dragging| *dragging.key() == key);
+    let grabbed = reorder.grabbed.as_ref() == Some(&key);
+
+    let style =
+        reorder.over.as_ref().filter(|(over, _)| *over == key).map(
+            |(_, position)| match position {
+                DropPosition::Before => {
+                    "box-shadow: inset 0 2px 0 0 var(--pf-v5-global--primary-color--100);"
+                }
+                DropPosition::Into => {
+                    "box-shadow: inset 0 0 0 2px var(--pf-v5-global--primary-color--100);"
+                }
+                DropPosition::After => {
+                    "box-shadow: inset 0 -2px 0 0 var(--pf-v5-global--primary-color--100);"
+                }
+            },
+        );
+
+    let ondragstart = {
+        let (ondrag, path) = (reorder.ondrag.clone(), path.clone());
+        Callback::from(move |evt: DragEvent| {
+            if let Some(transfer) = evt.data_transfer() {
+                transfer.set_effect_allowed("move");
+                // some browsers only start dragging when there is data
+                let _ = transfer.set_data("text/plain", "");
+            }
+            ondrag.emit(Some(path.clone()));
+        })
+    };
+
+    let ondragover = {
+        let (dragging, onover, path) = (
+            reorder.dragging.clone(),
+            reorder.onover.clone(),
+            path.clone(),
+        );
+        Callback::from(move |evt: DragEvent| {
+            // only accept our own rows, not e.g. files
+            let Some(from) = &dragging else {
+                return;
+            };
+            match drop_target(&evt, from, &path) {
+                Some((position, _)) => {
+                    evt.prevent_default();
+                    if let Some(transfer) = evt.data_transfer() {
+                        transfer.set_drop_effect("move");
+                    }
+                    onover.emit(Some((path.key().clone(), position)));
+                }
+                None => onover.emit(None),
+            }
+        })
+    };
+
+    let ondragend = {
+        let (ondrag, onover) = (reorder.ondrag.clone(), reorder.onover.clone());
+        Callback::from(move |_: DragEvent| {
+            ondrag.emit(None);
+            onover.emit(None);
+        })
+    };
+
+    let ondrop = {
+        let (dragging, onmove, ondragend, path) = (
+            reorder.dragging.clone(),
+            reorder.onmove.clone(),
+            ondragend.clone(),
+            path.clone(),
+        );
+        Callback::from(move |evt: DragEvent| {
+            let Some(from) = &dragging else {
+                return;
+            };
+            if let Some((_, target)) = drop_target(&evt, from, &path) {
+                evt.prevent_default();
+                onmove.emit(target);
+            }
+            ondragend.emit(evt);
+        })
+    };
+
+    let onkeydown = {
+        let (ongrab, onmove, path, previous) = (
+            reorder.ongrab.clone(),
+            reorder.onmove.clone(),
+            path.clone(),
+            previous.clone(),
+        );
+        Callback::from(move |evt: KeyboardEvent| {
+            match (grabbed, evt.key().as_str()) {
+                (false, " " | "Enter") => ongrab.emit(Some(key.clone())),
+                (true, " " | "Enter" | "Escape") => ongrab.emit(None),
+                (true, name @ ("ArrowUp" | "ArrowDown" | "ArrowLeft" | "ArrowRight")) => {
+                    if let Some(target) = keyboard_target(&path, name, size, previous.clone()) {
+                        onmove.emit(target);
+                    }
+                }
+                _ => return,
+            }
+            evt.prevent_default();
+        })
+    };
+
+    // release the node when the focus moves on, but not when moving the row drops the focus
+    let onblur = {
+        let ongrab = reorder.ongrab.clone();
+        Callback::from(move |evt: FocusEvent| {
+            if grabbed && evt.related_target().is_some() {
+                ongrab.emit(None);
+            }
+        })
+    };
+
+    RowDrag {
+        dragged,
+        style,
+        ondragstart,
+        ondragover,
+        ondrop,
+        ondragend,
+        handle: DragHandleProperties {
+            grabbed,
+            onkeydown,
+            onblur,
+            // the rows are focused using the roving tabindex
+            tabindex: Some(-1),
+        },
+    }
+}
+
+/// Evaluate dropping the dragged node onto a row, by the position of the pointer.
+fn drop_target(
+    evt: &DragEvent,
+    from: &NodePath,
+    target: &NodePath,
+) -> Option<(DropPosition, TreeReorder)> {
+    let row = evt.current_target()?.dyn_into::<Element>().ok()?;
+    let rect = row.get_bounding_client_rect();
+    let position = DropPosition::from_offset(evt.client_y() as f64 - rect.top(), rect.height());
+    position
+        .target(from, target)
+        .map(|reorder| (position, reorder))
+}
+
+/// Render a row, showing the status of loading the children of a lazy node.
+fn render_status(level: usize, columns: usize, content: Html) -> Html {
+    html!(
+        <tr
+            class="pf-v5-c-table__tr"
+            role="row"
+            aria-level={ level.to_string() }
+        >
+            // span all columns, including the one for the actions
+            <td
+                class="pf-v5-c-table__td pf-v5-c-table__tree-view-title-cell"
+                role="cell"
+                colspan={ (columns + 1).to_string() }
+            >
+                <div class="pf-v5-c-table__tree-view-main">
+                    <div class="pf-v5-c-table__tree-view-text">
+                        { content }
+                    </div>
+                </div>
+            </td>
+        </tr>
+    )
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 struct MainCellProperties {
     children: Html,
@@ -282,6 +949,10 @@ struct MainCellProperties {
     has_children: bool,
     expanded: bool,
     ontoggle: Callback<()>,
+    /// The selection state, and the callback to change it
+    select: Option<(CheckboxState, Callback<bool>)>,
+    /// The drag handle, when reordering
+    drag: Option<DragHandleProperties>,
 }
 
 #[function_component(MainCell)]
@@ -297,10 +968,16 @@ fn main_cell(props: &MainCellProperties) -> Html {
 
     html!(
         <div class="pf-v5-c-table__tree-view-main">
+            if let Some(drag) = &props.drag {
+                <span class="pf-v5-c-table__draggable">
+                    <DragHandle ..drag.clone() />
+                </span>
+            }
             if props.has_children {
                 <span class="pf-v5-c-table__toggle">
                     <button
                         type="button"
+                        tabindex="-1"
                         class={button_class}
                         aria-labelledby={format!("{} {}", *id_label, *id_toggle)}
                         id={ *id_toggle }
@@ -314,6 +991,15 @@ fn main_cell(props: &MainCellProperties) -> Html {
                     </button>
                 </span>
             }
+            if let Some((checked, onselect)) = &props.select {
+                <span class="pf-v5-c-table__check">
+                    <TableCheck
+                        checked={*checked}
+                        aria_label="Select row"
+                        onselect={onselect.reform(|evt: RowSelectEvent| evt.selected)}
+                    />
+                </span>
+            }
             <div class="pf-v5-c-table__tree-view-text">
                 <span
                     class="pf-v5-c-table__text"
@@ -327,6 +1013,7 @@ fn main_cell(props: &MainCellProperties) -> Html {
                 <button
                     class="pf-v5-c-button pf-m-plain"
                     type="button"
+                    tabindex="-1"
                 >
                     <span class="pf-v5-c-table__details-toggle-icon">
                         <i class="fas fa-ellipsis-h" aria-hidden="true"></i>
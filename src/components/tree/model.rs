@@ -1,11 +1,116 @@
 use crate::prelude::{Cell, CellContext};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
+use yew::{virtual_dom::Key, Html};
+
+/// The result of loading the children of a lazy [`TreeNode`].
+pub type TreeChildrenFuture<C> =
+    Pin<Box<dyn Future<Output = Result<Vec<Rc<dyn TreeNode<C>>>, String>>>>;
+
+/// The value of a column of a [`TreeNode`], used for sorting and filtering.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeValue {
+    Text(String),
+    Number(f64),
+}
+
+impl TreeValue {
+    /// Compare two values.
+    ///
+    /// Numbers are compared numerically, and sort before text.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            (Self::Number(_), Self::Text(_)) => Ordering::Less,
+            (Self::Text(_), Self::Number(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl Display for TreeValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(value) => f.write_str(value),
+            Self::Number(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<String> for TreeValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for TreeValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<f64> for TreeValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
 
 /// A node in a tree
 pub trait TreeNode<C> {
+    /// The key of the node, identifying it among all nodes of the tree.
+    ///
+    /// The expansion, selection, and loaded children of a node are tracked by its key, and it
+    /// keys the rendered rows. So it must be stable, even when the nodes get re-created (e.g. by
+    /// [`TreeNode::children`]), and should be derived from the data of the node.
+    fn key(&self) -> Key;
+
     fn render_cell(&self, ctx: CellContext<'_, C>) -> Cell;
 
     fn children(&self) -> Vec<Rc<dyn TreeNode<C>>>;
+
+    /// The value of a column, used for sorting and filtering the tree.
+    ///
+    /// Nodes without a value sort first, and never match a filter.
+    fn value(&self, _column: &C) -> Option<TreeValue> {
+        None
+    }
+
+    /// Check if the children of this node are loaded on demand.
+    ///
+    /// Lazy nodes start collapsed and load their children, using [`TreeNode::load_children`],
+    /// when being expanded. Until then, they are assumed to have children.
+    fn is_lazy(&self) -> bool {
+        false
+    }
+
+    /// Load the children of a lazy node.
+    ///
+    /// The default implementation returns the result of [`TreeNode::children`].
+    fn load_children(&self) -> TreeChildrenFuture<C>
+    where
+        C: 'static,
+    {
+        let children = self.children();
+        Box::pin(async move { Ok(children) })
+    }
+
+    /// An icon shown in front of the node, by a [`super::TreeView`].
+    fn icon(&self, _expanded: bool) -> Option<Html> {
+        None
+    }
+
+    /// A badge shown after the node, by a [`super::TreeView`], e.g. the number of children.
+    fn badge(&self) -> Option<Html> {
+        None
+    }
+
+    /// Actions of the node, shown by a [`super::TreeView`].
+    fn actions(&self) -> Option<Html> {
+        None
+    }
 }
 
 /// A model providing access to tree nodes
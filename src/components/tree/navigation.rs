@@ -0,0 +1,111 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Moving the focus between the rows of a tree grid, or the items of a tree view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RowNavigation {
+    Next,
+    Previous,
+    First,
+    Last,
+    Parent,
+    FirstChild,
+}
+
+impl RowNavigation {
+    /// Find the target row, based on the levels of all visible rows, in order.
+    pub(crate) fn target(&self, levels: &[usize], current: usize) -> Option<usize> {
+        let level = *levels.get(current)?;
+
+        match self {
+            Self::Next => (current + 1 < levels.len()).then_some(current + 1),
+            Self::Previous => current.checked_sub(1),
+            Self::First => (!levels.is_empty()).then_some(0),
+            Self::Last => levels.len().checked_sub(1),
+            Self::Parent => levels[..current].iter().rposition(|l| *l < level),
+            Self::FirstChild => levels
+                .get(current + 1)
+                .is_some_and(|l| *l == level + 1)
+                .then_some(current + 1),
+        }
+    }
+
+    /// Focus the target row, using the rows of the table body containing the current row.
+    pub(crate) fn focus(&self, row: &Element) {
+        if let Some(body) = row.parent_element() {
+            self.focus_within(&body, "tr[role=row][tabindex]:not([hidden])", row);
+        }
+    }
+
+    /// Focus the target item, using the items of the container matching the selector, in
+    /// document order. Items need to provide their level using the `aria-level` attribute.
+    pub(crate) fn focus_within(&self, container: &Element, selector: &str, current: &Element) {
+        let Ok(nodes) = container.query_selector_all(selector) else {
+            return;
+        };
+
+        let rows = (0..nodes.length())
+            .filter_map(|i| nodes.item(i)?.dyn_into::<HtmlElement>().ok())
+            .collect::<Vec<_>>();
+        let levels = rows
+            .iter()
+            .map(|row| {
+                row.get_attribute("aria-level")
+                    .and_then(|level| level.parse().ok())
+                    .unwrap_or(1)
+            })
+            .collect::<Vec<_>>();
+
+        let target = rows
+            .iter()
+            .position(|r| r.is_same_node(Some(current)))
+            .and_then(|current| self.target(&levels, current));
+
+        if let Some(target) = target {
+            let _ = rows[target].focus();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0: a
+    /// 1:   a1
+    /// 2:     a1x
+    /// 3:   a2
+    /// 4: b
+    const LEVELS: &[usize] = &[1, 2, 3, 2, 1];
+
+    #[test]
+    fn test_siblings() {
+        assert_eq!(RowNavigation::Next.target(LEVELS, 0), Some(1));
+        assert_eq!(RowNavigation::Next.target(LEVELS, 4), None);
+        assert_eq!(RowNavigation::Previous.target(LEVELS, 3), Some(2));
+        assert_eq!(RowNavigation::Previous.target(LEVELS, 0), None);
+        assert_eq!(RowNavigation::First.target(LEVELS, 3), Some(0));
+        assert_eq!(RowNavigation::Last.target(LEVELS, 1), Some(4));
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(RowNavigation::Parent.target(LEVELS, 2), Some(1));
+        assert_eq!(RowNavigation::Parent.target(LEVELS, 3), Some(0));
+        assert_eq!(RowNavigation::Parent.target(LEVELS, 4), None);
+    }
+
+    #[test]
+    fn test_first_child() {
+        assert_eq!(RowNavigation::FirstChild.target(LEVELS, 0), Some(1));
+        assert_eq!(RowNavigation::FirstChild.target(LEVELS, 2), None);
+        assert_eq!(RowNavigation::FirstChild.target(LEVELS, 3), None);
+        assert_eq!(RowNavigation::FirstChild.target(LEVELS, 4), None);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(RowNavigation::Next.target(&[], 0), None);
+        assert_eq!(RowNavigation::Last.target(LEVELS, 5), None);
+    }
+}
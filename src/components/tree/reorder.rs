@@ -0,0 +1,315 @@
+use std::rc::Rc;
+use yew::{prelude::*, virtual_dom::Key};
+
+/// A request to move a node of a [`super::TreeTable`], possibly to a different parent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeReorder {
+    /// The key of the moved node
+    pub key: Key,
+    /// The key of the new parent, `None` for the top level
+    pub parent: Option<Key>,
+    /// The index among the children of the new parent, after the node got moved
+    pub index: usize,
+}
+
+/// The location of a row in the tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct NodePath(Rc<Vec<(Key, usize)>>);
+
+impl NodePath {
+    /// A node on the top level.
+    pub(crate) fn root(key: Key, index: usize) -> Self {
+        Self(Rc::new(vec![(key, index)]))
+    }
+
+    /// A child of this node.
+    pub(crate) fn child(&self, key: Key, index: usize) -> Self {
+        let mut path = (*self.0).clone();
+        path.push((key, index));
+        Self(Rc::new(path))
+    }
+
+    pub(crate) fn key(&self) -> &Key {
+        &self.last().0
+    }
+
+    /// The level in the tree, starting with 1 for the top level.
+    pub(crate) fn level(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The index among its siblings.
+    pub(crate) fn index(&self) -> usize {
+        self.last().1
+    }
+
+    fn last(&self) -> &(Key, usize) {
+        // never empty, as it always contains the node itself
+        &self.0[self.0.len() - 1]
+    }
+
+    fn parent(&self) -> Option<&(Key, usize)> {
+        self.0.len().checked_sub(2).map(|parent| &self.0[parent])
+    }
+
+    fn parent_key(&self) -> Option<Key> {
+        self.parent().map(|(key, _)| key.clone())
+    }
+
+    /// Check if a node is this node, or one of its ancestors.
+    fn contains(&self, key: &Key) -> bool {
+        self.0.iter().any(|(k, _)| k == key)
+    }
+
+    /// Move this node to a new location, unless it stays where it is.
+    fn moved(&self, parent: Option<Key>, index: usize) -> Option<TreeReorder> {
+        let same_parent = parent == self.parent_key();
+        // the node itself no longer takes up a place before the target
+        let index = match same_parent && self.index() < index {
+            true => index - 1,
+            false => index,
+        };
+
+        (!same_parent || index != self.index()).then(|| TreeReorder {
+            key: self.key().clone(),
+            parent,
+            index,
+        })
+    }
+}
+
+/// Where to drop a dragged node, relative to the row it is dragged over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DropPosition {
+    /// Before the row, as its sibling
+    Before,
+    /// Into the row, as its first child
+    Into,
+    /// After the row, as its sibling
+    After,
+}
+
+impl DropPosition {
+    /// Evaluate the position by the vertical offset into the row.
+    ///
+    /// The upper and lower quarter of a row drop before or after the row.
+    pub(crate) fn from_offset(offset: f64, height: f64) -> Self {
+        if offset < height / 4.0 {
+            Self::Before
+        } else if offset > height * 3.0 / 4.0 {
+            Self::After
+        } else {
+            Self::Into
+        }
+    }
+
+    /// Evaluate the move of dropping a node onto a target row.
+    ///
+    /// Returns `None` if the node would not move, or would become its own descendant.
+    pub(crate) fn target(&self, from: &NodePath, target: &NodePath) -> Option<TreeReorder> {
+        if target.contains(from.key()) {
+            return None;
+        }
+
+        match self {
+            Self::Before => from.moved(target.parent_key(), target.index()),
+            Self::After => from.moved(target.parent_key(), target.index() + 1),
+            Self::Into => from.moved(Some(target.key().clone()), 0),
+        }
+    }
+}
+
+/// Evaluate the move of a grabbed node, by a key press.
+///
+/// Up and down move the node among its siblings, left moves it after its parent, and right
+/// appends it to the children of its previous sibling. The siblings are `size` nodes, `previous`
+/// is the key and the number of known children of the previous sibling.
+///
+/// Returns `None` if the node doesn't move.
+pub(crate) fn keyboard_target(
+    from: &NodePath,
+    key: &str,
+    size: usize,
+    previous: Option<(Key, usize)>,
+) -> Option<TreeReorder> {
+    let index = from.index();
+    match key {
+        "ArrowUp" => from.moved(from.parent_key(), index.checked_sub(1)?),
+        // the target is evaluated before removing the node, so skip the next sibling
+        "ArrowDown" => from.moved(from.parent_key(), (index + 1 < size).then_some(index + 2)?),
+        "ArrowLeft" => {
+            let (_, parent) = from.parent()?;
+            let grandparent = from.0.len().checked_sub(3).map(|n| from.0[n].0.clone());
+            from.moved(grandparent, parent + 1)
+        }
+        "ArrowRight" => {
+            let (previous, children) = previous?;
+            from.moved(Some(previous), children)
+        }
+        _ => None,
+    }
+}
+
+/// The drag and drop state of a tree, shared by all of its rows.
+#[derive(Clone, PartialEq)]
+pub(crate) struct NodeReorder {
+    /// The node being dragged using the mouse
+    pub(crate) dragging: Option<NodePath>,
+    /// The row the node is dragged over
+    pub(crate) over: Option<(Key, DropPosition)>,
+    /// The node grabbed using the keyboard
+    pub(crate) grabbed: Option<Key>,
+    /// The parent a node was moved into, which should get expanded, along with a counter
+    /// telling subsequent moves apart
+    pub(crate) reveal: Option<(Key, usize)>,
+    pub(crate) ondrag: Callback<Option<NodePath>>,
+    pub(crate) onover: Callback<Option<(Key, DropPosition)>>,
+    pub(crate) ongrab: Callback<Option<Key>>,
+    /// Report a move, revealing its new location
+    pub(crate) onmove: Callback<TreeReorder>,
+}
+
+/// Track dragging and grabbing nodes, if the nodes can be reordered.
+#[hook]
+pub(crate) fn use_node_reorder(onreorder: Option<Callback<TreeReorder>>) -> Option<NodeReorder> {
+    let dragging = use_state_eq(|| None);
+    let over = use_state_eq(|| None);
+    let grabbed = use_state_eq(|| None);
+    let reveal = use_state_eq(|| None::<(Key, usize)>);
+
+    let ondrag = use_callback(dragging.clone(), |value, dragging| dragging.set(value));
+    let onover = use_callback(over.clone(), |value, over| over.set(value));
+    let ongrab = use_callback(grabbed.clone(), |value, grabbed| grabbed.set(value));
+    let onmove = use_callback(
+        (reveal.clone(), onreorder.clone()),
+        |value: TreeReorder, (reveal, onreorder)| {
+            let count = reveal.as_ref().map_or(0, |(_, count)| count + 1);
+            reveal.set(value.parent.clone().map(|parent| (parent, count)));
+            if let Some(onreorder) = onreorder {
+                onreorder.emit(value);
+            }
+        },
+    );
+
+    onreorder.map(|_| NodeReorder {
+        dragging: (*dragging).clone(),
+        over: (*over).clone(),
+        grabbed: (*grabbed).clone(),
+        reveal: (*reveal).clone(),
+        ondrag,
+        onover,
+        ongrab,
+        onmove,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// a: [ a1: [ a1x ], a2 ], b
+    fn path(keys: &[(&'static str, usize)]) -> NodePath {
+        let mut path = NodePath::root(keys[0].0.into(), keys[0].1);
+        for (key, index) in &keys[1..] {
+            path = path.child((*key).into(), *index);
+        }
+        path
+    }
+
+    fn reorder(key: &'static str, parent: Option<&'static str>, index: usize) -> TreeReorder {
+        TreeReorder {
+            key: key.into(),
+            parent: parent.map(Key::from),
+            index,
+        }
+    }
+
+    #[test]
+    fn test_drop_position() {
+        assert_eq!(DropPosition::from_offset(1.0, 20.0), DropPosition::Before);
+        assert_eq!(DropPosition::from_offset(10.0, 20.0), DropPosition::Into);
+        assert_eq!(DropPosition::from_offset(19.0, 20.0), DropPosition::After);
+    }
+
+    #[test]
+    fn test_drop_siblings() {
+        let a = path(&[("a", 0)]);
+        let b = path(&[("b", 1)]);
+
+        assert_eq!(
+            DropPosition::After.target(&a, &b),
+            Some(reorder("a", None, 1))
+        );
+        assert_eq!(
+            DropPosition::Before.target(&b, &a),
+            Some(reorder("b", None, 0))
+        );
+        // dropping next to its current place doesn't move
+        assert_eq!(DropPosition::Before.target(&a, &b), None);
+        assert_eq!(DropPosition::After.target(&b, &a), None);
+    }
+
+    #[test]
+    fn test_drop_reparent() {
+        let a1x = path(&[("a", 0), ("a1", 0), ("a1x", 0)]);
+        let a2 = path(&[("a", 0), ("a2", 1)]);
+        let b = path(&[("b", 1)]);
+
+        assert_eq!(
+            DropPosition::Into.target(&a1x, &b),
+            Some(reorder("a1x", Some("b"), 0))
+        );
+        assert_eq!(
+            DropPosition::After.target(&a1x, &a2),
+            Some(reorder("a1x", Some("a"), 2))
+        );
+        assert_eq!(
+            DropPosition::Before.target(&b, &a2),
+            Some(reorder("b", Some("a"), 1))
+        );
+    }
+
+    #[test]
+    fn test_drop_into_itself() {
+        let a = path(&[("a", 0)]);
+        let a1x = path(&[("a", 0), ("a1", 0), ("a1x", 0)]);
+
+        assert_eq!(DropPosition::Into.target(&a, &a), None);
+        assert_eq!(DropPosition::Into.target(&a, &a1x), None);
+        assert_eq!(DropPosition::After.target(&a, &a1x), None);
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let a2 = path(&[("a", 0), ("a2", 1)]);
+
+        assert_eq!(
+            keyboard_target(&a2, "ArrowUp", 2, None),
+            Some(reorder("a2", Some("a"), 0))
+        );
+        assert_eq!(keyboard_target(&a2, "ArrowDown", 2, None), None);
+        assert_eq!(
+            keyboard_target(&a2, "ArrowLeft", 2, None),
+            Some(reorder("a2", None, 1))
+        );
+        assert_eq!(
+            keyboard_target(&a2, "ArrowRight", 2, Some(("a1".into(), 1))),
+            Some(reorder("a2", Some("a1"), 1))
+        );
+        assert_eq!(keyboard_target(&a2, "Enter", 2, None), None);
+
+        let b = path(&[("b", 1)]);
+        assert_eq!(keyboard_target(&b, "ArrowLeft", 2, None), None);
+        assert_eq!(keyboard_target(&b, "ArrowDown", 2, None), None);
+
+        let a1 = path(&[("a", 0), ("a1", 0)]);
+        assert_eq!(
+            keyboard_target(&a1, "ArrowDown", 2, None),
+            Some(reorder("a1", Some("a"), 1))
+        );
+        assert_eq!(
+            keyboard_target(&a1, "ArrowDown", 3, None),
+            Some(reorder("a1", Some("a"), 1))
+        );
+    }
+}
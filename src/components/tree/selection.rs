@@ -0,0 +1,410 @@
+use super::{TreeNode, TreeTableModel};
+use crate::prelude::CheckboxState;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use yew::{prelude::*, virtual_dom::Key};
+
+/// A set of selected tree nodes, identified by their keys.
+///
+/// Selecting a node selects all of its descendants. A node having children is only selected
+/// when all of its children are selected, otherwise it is either partially selected (some
+/// descendants are selected) or not selected.
+///
+/// The descendants of lazy nodes (see [`TreeNode::is_lazy`]) are not known to the selection, so
+/// lazy nodes are handled like nodes without children. When used by a [`super::TreeTable`], the
+/// loaded children of lazy nodes are taken into account, and inherit the selection of their
+/// parent when being loaded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeSelection {
+    selected: HashSet<Key>,
+}
+
+impl From<HashSet<Key>> for TreeSelection {
+    fn from(selected: HashSet<Key>) -> Self {
+        Self { selected }
+    }
+}
+
+impl From<TreeSelection> for HashSet<Key> {
+    fn from(value: TreeSelection) -> Self {
+        value.selected
+    }
+}
+
+/// Provides the children of a node, as far as they are known.
+type KnownChildren<'a, C> = &'a dyn Fn(&Rc<dyn TreeNode<C>>) -> Vec<Rc<dyn TreeNode<C>>>;
+
+/// The children of a node, as far as they are known without loading them.
+fn known_children<C>(node: &Rc<dyn TreeNode<C>>) -> Vec<Rc<dyn TreeNode<C>>> {
+    match node.is_lazy() {
+        true => vec![],
+        false => node.children(),
+    }
+}
+
+/// The loaded children of lazy nodes, by the key of their parent.
+type LoadedChildren<C> = HashMap<Key, Vec<Rc<dyn TreeNode<C>>>>;
+
+/// A lazy node, and its loaded children.
+type LoadedNode<C> = (Rc<dyn TreeNode<C>>, Vec<Rc<dyn TreeNode<C>>>);
+
+/// The children of a node, including the loaded children of lazy nodes.
+fn loaded_children<C>(
+    loaded: &LoadedChildren<C>,
+    node: &Rc<dyn TreeNode<C>>,
+) -> Vec<Rc<dyn TreeNode<C>>> {
+    match node.is_lazy() {
+        true => loaded.get(&node.key()).cloned().unwrap_or_default(),
+        false => node.children(),
+    }
+}
+
+impl TreeSelection {
+    pub fn is_selected(&self, key: &Key) -> bool {
+        self.selected.contains(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Iterate over the selected keys, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Key> {
+        self.selected.iter()
+    }
+
+    /// The selected keys.
+    pub fn keys(&self) -> &HashSet<Key> {
+        &self.selected
+    }
+
+    /// Deselect all nodes.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Select or deselect a node, including all of its descendants.
+    ///
+    /// This doesn't update the ancestors of the node, use [`Self::normalize`] for that.
+    pub fn set<C>(&mut self, node: &Rc<dyn TreeNode<C>>, selected: bool) {
+        self.set_with(node, selected, &known_children);
+    }
+
+    fn set_with<C>(
+        &mut self,
+        node: &Rc<dyn TreeNode<C>>,
+        selected: bool,
+        children: KnownChildren<'_, C>,
+    ) {
+        match selected {
+            true => self.selected.insert(node.key()),
+            false => self.selected.remove(&node.key()),
+        };
+        for child in children(node) {
+            self.set_with(&child, selected, children);
+        }
+    }
+
+    /// Update the selection of all nodes having children, so that they are selected if, and
+    /// only if, all of their children are selected.
+    pub fn normalize<C>(&mut self, roots: &[Rc<dyn TreeNode<C>>]) {
+        self.normalize_with(roots, &known_children);
+    }
+
+    fn normalize_with<C>(&mut self, roots: &[Rc<dyn TreeNode<C>>], children: KnownChildren<'_, C>) {
+        let known = children;
+        for node in roots {
+            let children = known(node);
+            if children.is_empty() {
+                continue;
+            }
+
+            self.normalize_with(&children, known);
+
+            match children
+                .iter()
+                .all(|child| self.selected.contains(&child.key()))
+            {
+                true => self.selected.insert(node.key()),
+                false => self.selected.remove(&node.key()),
+            };
+        }
+    }
+
+    /// Evaluate the selection state of the provided nodes and all of their descendants.
+    pub fn states<C>(&self, roots: &[Rc<dyn TreeNode<C>>]) -> HashMap<Key, CheckboxState> {
+        self.states_with(roots, &known_children)
+    }
+
+    fn states_with<C>(
+        &self,
+        roots: &[Rc<dyn TreeNode<C>>],
+        children: KnownChildren<'_, C>,
+    ) -> HashMap<Key, CheckboxState> {
+        let mut result = HashMap::new();
+        for node in roots {
+            self.collect_state(node, children, &mut result);
+        }
+        result
+    }
+
+    fn collect_state<C>(
+        &self,
+        node: &Rc<dyn TreeNode<C>>,
+        known: KnownChildren<'_, C>,
+        states: &mut HashMap<Key, CheckboxState>,
+    ) -> CheckboxState {
+        let children = known(node);
+
+        let state = match children.is_empty() {
+            true => self.selected.contains(&node.key()).into(),
+            false => {
+                let mut any = false;
+                let mut all = true;
+                for child in &children {
+                    match self.collect_state(child, known, states) {
+                        CheckboxState::Checked => any = true,
+                        CheckboxState::Indeterminate => {
+                            any = true;
+                            all = false;
+                        }
+                        CheckboxState::Unchecked => all = false,
+                    }
+                }
+                match (any, all) {
+                    (_, true) => CheckboxState::Checked,
+                    (true, false) => CheckboxState::Indeterminate,
+                    (false, false) => CheckboxState::Unchecked,
+                }
+            }
+        };
+
+        states.insert(node.key(), state);
+        state
+    }
+}
+
+/// The selection state of a tree, shared by all of its nodes.
+pub(crate) struct NodeSelection<C> {
+    selected: Rc<TreeSelection>,
+    states: Rc<HashMap<Key, CheckboxState>>,
+    pub(crate) onselect: Callback<(Rc<dyn TreeNode<C>>, bool)>,
+    /// Report the loaded children of a lazy node
+    pub(crate) onloaded: Callback<LoadedNode<C>>,
+}
+
+impl<C> NodeSelection<C> {
+    pub(crate) fn state(&self, key: &Key) -> CheckboxState {
+        match self.states.get(key) {
+            Some(state) => *state,
+            // children of lazy nodes are not part of the evaluated states
+            None => self.selected.is_selected(key).into(),
+        }
+    }
+}
+
+impl<C> PartialEq for NodeSelection<C> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.selected, &other.selected)
+            && Rc::ptr_eq(&self.states, &other.states)
+            && self.onselect == other.onselect
+            && self.onloaded == other.onloaded
+    }
+}
+
+/// Track the selection of the nodes of a model, unless controlled by `selected`.
+///
+/// Returns `None` if the nodes are not `selectable`.
+#[hook]
+pub(crate) fn use_node_selection<C, M>(
+    model: Rc<M>,
+    selectable: bool,
+    selected: Option<TreeSelection>,
+    onselect: Callback<TreeSelection>,
+) -> Option<NodeSelection<C>>
+where
+    C: 'static,
+    M: TreeTableModel<C> + PartialEq + 'static,
+{
+    let selection = use_state_eq(TreeSelection::default);
+    let selected = use_memo((selected, (*selection).clone()), |(selected, selection)| {
+        selected.clone().unwrap_or_else(|| selection.clone())
+    });
+
+    // the loaded children of lazy nodes, and a version, tracking changes
+    let loaded = use_mut_ref(|| (0usize, LoadedChildren::<C>::new()));
+    let trigger = use_force_update();
+
+    let states = {
+        let loaded = loaded.clone();
+        let version = loaded.borrow().0;
+        use_memo(
+            (model.clone(), selected.clone(), selectable, version),
+            move |(model, selected, selectable, _)| match selectable {
+                true => {
+                    let loaded = &loaded.borrow().1;
+                    selected.states_with(&model.children(), &|node| loaded_children(loaded, node))
+                }
+                false => HashMap::new(),
+            },
+        )
+    };
+
+    let onselect_node = {
+        let loaded = loaded.clone();
+        use_callback(
+            (
+                model.clone(),
+                selected.clone(),
+                selection.clone(),
+                onselect.clone(),
+            ),
+            move |(node, value): (Rc<dyn TreeNode<C>>, bool),
+                  (model, selected, selection, onselect)| {
+                let loaded = &loaded.borrow().1;
+                let children = |node: &Rc<dyn TreeNode<C>>| loaded_children(loaded, node);
+
+                let mut next = (**selected).clone();
+                next.set_with(&node, value, &children);
+                next.normalize_with(&model.children(), &children);
+                selection.set(next.clone());
+                onselect.emit(next);
+            },
+        )
+    };
+
+    let onloaded = use_callback(
+        (model, selected.clone(), selection, onselect),
+        move |(node, nodes): LoadedNode<C>, (model, selected, selection, onselect)| {
+            {
+                let mut loaded = loaded.borrow_mut();
+                loaded.0 += 1;
+                loaded.1.insert(node.key(), nodes.clone());
+            }
+            trigger.force_update();
+
+            // children of a selected node are selected as well
+            if selected.is_selected(&node.key()) {
+                let loaded = &loaded.borrow().1;
+                let children = |node: &Rc<dyn TreeNode<C>>| loaded_children(loaded, node);
+
+                let mut next = (**selected).clone();
+                for child in &nodes {
+                    next.set_with(child, true, &children);
+                }
+                next.normalize_with(&model.children(), &children);
+                if next != **selected {
+                    selection.set(next.clone());
+                    onselect.emit(next);
+                }
+            }
+        },
+    );
+
+    selectable.then_some(NodeSelection {
+        selected,
+        states,
+        onselect: onselect_node,
+        onloaded,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::fixture::{lazy, node, tree};
+    use super::*;
+
+    fn selected(selection: &TreeSelection) -> Vec<String> {
+        let mut result = selection
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+
+    fn state(
+        selection: &TreeSelection,
+        roots: &[Rc<dyn TreeNode<()>>],
+        key: &str,
+    ) -> CheckboxState {
+        selection.states(roots)[&Key::from(key)]
+    }
+
+    #[test]
+    fn test_select_descendants() {
+        let roots = tree();
+        let mut selection = TreeSelection::default();
+
+        selection.set(&roots[0], true);
+        selection.normalize(&roots);
+        assert_eq!(selected(&selection), vec!["a", "a1", "a1x", "a1y", "a2"]);
+        assert_eq!(state(&selection, &roots, "a"), CheckboxState::Checked);
+        assert_eq!(state(&selection, &roots, "b"), CheckboxState::Unchecked);
+    }
+
+    #[test]
+    fn test_partial() {
+        let roots = tree();
+        let a1 = roots[0].children()[0].clone();
+        let mut selection = TreeSelection::default();
+
+        selection.set(&a1.children()[0], true);
+        selection.normalize(&roots);
+        assert_eq!(selected(&selection), vec!["a1x"]);
+        assert_eq!(state(&selection, &roots, "a"), CheckboxState::Indeterminate);
+        assert_eq!(
+            state(&selection, &roots, "a1"),
+            CheckboxState::Indeterminate
+        );
+        assert_eq!(state(&selection, &roots, "a2"), CheckboxState::Unchecked);
+
+        // completing the children selects the parent, but not the grandparent
+        selection.set(&a1.children()[1], true);
+        selection.normalize(&roots);
+        assert_eq!(selected(&selection), vec!["a1", "a1x", "a1y"]);
+        assert_eq!(state(&selection, &roots, "a1"), CheckboxState::Checked);
+        assert_eq!(state(&selection, &roots, "a"), CheckboxState::Indeterminate);
+    }
+
+    #[test]
+    fn test_loaded_children() {
+        let roots = vec![tree()[0].clone(), lazy("b")];
+        let a1x = roots[0].children()[0].children()[0].clone();
+        let mut loaded = LoadedChildren::new();
+        loaded.insert(Key::from("b"), vec![node("b1", vec![]), node("b2", vec![])]);
+        let children = |node: &Rc<dyn TreeNode<()>>| loaded_children(&loaded, node);
+
+        let mut selection = TreeSelection::default();
+        selection.set_with(&roots[1], true, &children);
+        selection.set_with(&a1x, true, &children);
+        selection.normalize_with(&roots, &children);
+        assert_eq!(selected(&selection), vec!["a1x", "b", "b1", "b2"]);
+
+        // deselecting a loaded child updates the parent
+        let b1 = children(&roots[1])[0].clone();
+        selection.set_with(&b1, false, &children);
+        selection.normalize_with(&roots, &children);
+        assert_eq!(selected(&selection), vec!["a1x", "b2"]);
+        assert_eq!(
+            selection.states_with(&roots, &children)[&Key::from("b")],
+            CheckboxState::Indeterminate
+        );
+    }
+
+    #[test]
+    fn test_deselect() {
+        let roots = tree();
+        let mut selection = TreeSelection::default();
+
+        selection.set(&roots[0], true);
+        selection.set(&roots[0].children()[1], false);
+        selection.normalize(&roots);
+        assert_eq!(selected(&selection), vec!["a1", "a1x", "a1y"]);
+        assert_eq!(state(&selection, &roots, "a"), CheckboxState::Indeterminate);
+    }
+}
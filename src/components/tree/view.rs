@@ -0,0 +1,534 @@
+use super::filter::filter_nodes;
+use super::navigation::RowNavigation;
+use super::{use_node_selection, NodeSelection, TreeNode, TreeSelection, TreeTableModel};
+use crate::prelude::{use_random_id, CellContext, CheckboxState, SearchInput};
+use std::collections::HashSet;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::{Element, HtmlInputElement};
+use yew::{prelude::*, virtual_dom::Key};
+
+/// Check if a node matches a search term.
+pub type TreeSearch<C> = Callback<(Rc<dyn TreeNode<C>>, String), bool>;
+
+/// Properties for [`TreeView`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct TreeViewProperties<C, M>
+where
+    C: Clone + Eq + 'static,
+    M: TreeTableModel<C> + PartialEq,
+{
+    #[prop_or_default]
+    pub id: AttrValue,
+
+    pub model: Rc<M>,
+
+    /// The column used to render the name of a node.
+    pub column: C,
+
+    #[prop_or_default]
+    pub default_expansion: bool,
+
+    /// Show guides, connecting nodes with their parent.
+    #[prop_or_default]
+    pub guides: bool,
+
+    /// Render a selection checkbox for each node.
+    ///
+    /// Selecting a node selects all of its descendants, also see [`TreeSelection`].
+    #[prop_or_default]
+    pub checkboxes: bool,
+
+    /// The selected nodes, controlling the selection.
+    ///
+    /// If not set, the view keeps track of the selection itself.
+    #[prop_or_default]
+    pub selected: Option<TreeSelection>,
+
+    /// Called with the new selection, when the user changes the selection.
+    #[prop_or_default]
+    pub onselect: Callback<TreeSelection>,
+
+    /// Show a search input, filtering the nodes.
+    ///
+    /// The callback is called with a node and the search term, and must return `true` if the
+    /// node matches. Matching nodes are shown along with their ancestors, which get expanded.
+    #[prop_or_default]
+    pub search: Option<TreeSearch<C>>,
+
+    #[prop_or(AttrValue::from("Search"))]
+    pub search_placeholder: AttrValue,
+}
+
+/// Tree view component
+///
+/// > A **tree view** is a structure that displays data in a hierarchical view.
+///
+/// See: <https://www.patternfly.org/components/tree-view>
+///
+/// The tree view uses the same model as the [`super::TreeTable`], rendering the cell of a single
+/// column as the name of a node. Icons, badges, and actions of a node are provided by the
+/// [`TreeNode`]. Lazy nodes are not supported by the tree view, which only shows the children
+/// provided by [`TreeNode::children`].
+///
+/// ## Properties
+///
+/// Defined by [`TreeViewProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use yew::{prelude::*, virtual_dom::Key};
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// struct Name;
+///
+/// struct Folder {
+///   name: &'static str,
+///   children: Vec<Rc<dyn TreeNode<Name>>>,
+/// }
+///
+/// impl TreeNode<Name> for Folder {
+///   fn key(&self) -> Key {
+///     self.name.into()
+///   }
+///
+///   fn render_cell(&self, _ctx: CellContext<'_, Name>) -> Cell {
+///     html!(self.name).into()
+///   }
+///
+///   fn children(&self) -> Vec<Rc<dyn TreeNode<Name>>> {
+///     self.children.clone()
+///   }
+///
+///   fn icon(&self, expanded: bool) -> Option<Html> {
+///     Some(match expanded {
+///       true => Icon::FolderOpen.into(),
+///       false => Icon::Folder.into(),
+///     })
+///   }
+/// }
+///
+/// #[derive(PartialEq)]
+/// struct Model;
+///
+/// impl TreeTableModel<Name> for Model {
+///   fn children(&self) -> Vec<Rc<dyn TreeNode<Name>>> {
+///     vec![Rc::new(Folder { name: "src", children: vec![
+///       Rc::new(Folder { name: "components", children: vec![] }),
+///     ] })]
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let model = use_memo((), |()| Model);
+///   let search = Callback::from(|(node, term): (Rc<dyn TreeNode<Name>>, String)| {
+///     node.key().to_string().contains(&term)
+///   });
+///
+///   html!(
+///     <TreeView<Name, Model> {model} column={Name} checkboxes=true {search} />
+///   )
+/// }
+/// ```
+#[function_component(TreeView)]
+pub fn tree_view<C, M>(props: &TreeViewProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: TreeTableModel<C> + PartialEq + 'static,
+{
+    let mut class = classes!("pf-v5-c-tree-view");
+
+    if props.guides {
+        class.push(classes!("pf-m-guides"));
+    }
+
+    let term = use_state_eq(String::new);
+    let searching = props.search.is_some() && !term.is_empty();
+
+    let nodes = use_memo(
+        (props.model.clone(), props.search.clone(), (*term).clone()),
+        |(model, search, term)| match search {
+            Some(search) if !term.is_empty() => filter_nodes(
+                &model.children(),
+                &|node| search.emit((node.clone(), term.clone())),
+                // all nodes get expanded while searching
+                &mut HashSet::new(),
+            ),
+            _ => model.children(),
+        },
+    );
+
+    let selection = use_node_selection(
+        props.model.clone(),
+        props.checkboxes,
+        props.selected.clone(),
+        props.onselect.clone(),
+    );
+
+    // the item which can be reached using the tab key
+    let focused = use_state_eq(|| None::<Key>);
+    let onfocus = use_callback(focused.clone(), |key: Key, focused| focused.set(Some(key)));
+
+    let context = Rc::new(ViewContext {
+        column: props.column.clone(),
+        default_expansion: props.default_expansion,
+        searching,
+        selection,
+        focused: (*focused).clone(),
+        onfocus,
+    });
+
+    let onchange = use_callback(term.clone(), |value: String, term| term.set(value));
+    let onclear = use_callback(term.clone(), |_: MouseEvent, term| term.set(String::new()));
+
+    html!(
+        <div id={&props.id} {class}>
+            if props.search.is_some() {
+                <div class="pf-v5-c-tree-view__search">
+                    <SearchInput
+                        placeholder={props.search_placeholder.clone()}
+                        value={(*term).clone()}
+                        {onchange}
+                        {onclear}
+                    />
+                </div>
+                <hr class="pf-v5-c-divider" />
+            }
+            <ul class="pf-v5-c-tree-view__list" role="tree">
+                { render_nodes(&nodes, 1, &context) }
+            </ul>
+        </div>
+    )
+}
+
+/// Information shared by all nodes of a tree view.
+struct ViewContext<C>
+where
+    C: Clone + Eq + 'static,
+{
+    column: C,
+    default_expansion: bool,
+    searching: bool,
+    selection: Option<NodeSelection<C>>,
+    /// The key of the item which last had the focus
+    focused: Option<Key>,
+    onfocus: Callback<Key>,
+}
+
+impl<C> PartialEq for ViewContext<C>
+where
+    C: Clone + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.column == other.column
+            && self.default_expansion == other.default_expansion
+            && self.searching == other.searching
+            && self.selection == other.selection
+            && self.focused == other.focused
+            && self.onfocus == other.onfocus
+    }
+}
+
+fn render_nodes<C>(
+    nodes: &[Rc<dyn TreeNode<C>>],
+    level: usize,
+    context: &Rc<ViewContext<C>>,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+{
+    html!(
+        { for nodes.iter().enumerate().map(|(position, node)| html!(
+            <Item<C> key={node.key()} node={node.clone()} {level} {position} context={context.clone()} />
+        )) }
+    )
+}
+
+#[derive(Properties)]
+struct ItemProperties<C>
+where
+    C: Clone + Eq + 'static,
+{
+    node: Rc<dyn TreeNode<C>>,
+    /// The level of the item, starting with 1
+    level: usize,
+    /// The position among its siblings
+    position: usize,
+    context: Rc<ViewContext<C>>,
+}
+
+impl<C> PartialEq for ItemProperties<C>
+where
+    C: Clone + Eq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.node, &other.node)
+            && self.level == other.level
+            && self.position == other.position
+            && self.context == other.context
+    }
+}
+
+#[function_component(Item)]
+fn item<C>(props: &ItemProperties<C>) -> Html
+where
+    C: Clone + Eq + 'static,
+{
+    let expanded = use_state_eq(|| props.context.default_expansion);
+
+    // reveal the matches when starting to search
+    {
+        let expanded = expanded.clone();
+        use_effect_with(props.context.searching, move |searching| {
+            if *searching {
+                expanded.set(true);
+            }
+        });
+    }
+
+    let children = props.node.children();
+    let has_children = !children.is_empty();
+
+    let mut class = classes!("pf-v5-c-tree-view__list-item");
+    if has_children {
+        class.push(classes!("pf-m-expandable"));
+        if *expanded {
+            class.push(classes!("pf-m-expanded"));
+        }
+    }
+
+    let ontoggle = use_callback(expanded.clone(), |evt: MouseEvent, expanded| {
+        // don't toggle the checkbox of the surrounding label
+        evt.prevent_default();
+        expanded.set(!**expanded);
+    });
+
+    let cell = props.node.render_cell(CellContext {
+        column: &props.context.column,
+    });
+
+    let node = html!(
+        <>
+            if let Some(icon) = props.node.icon(*expanded) {
+                <span class="pf-v5-c-tree-view__node-icon">{ icon }</span>
+            }
+            <span class="pf-v5-c-tree-view__node-text">{ cell.content }</span>
+            if let Some(badge) = props.node.badge() {
+                <span class="pf-v5-c-tree-view__node-count">{ badge }</span>
+            }
+        </>
+    );
+
+    let toggle_icon = html!(
+        <span class="pf-v5-c-tree-view__node-toggle-icon">
+            <i class="fas fa-angle-right" aria-hidden="true"></i>
+        </span>
+    );
+
+    let select = props.context.selection.as_ref().map(|selection| {
+        let node = props.node.clone();
+        (
+            selection.state(&props.node.key()),
+            selection
+                .onselect
+                .reform(move |selected| (node.clone(), selected)),
+        )
+    });
+
+    // roving tabindex, only a single item can be reached using the tab key
+    let key = props.node.key();
+    let tabindex = match &props.context.focused {
+        Some(focused) => *focused == key,
+        None => props.level == 1 && props.position == 0,
+    };
+
+    // events of nested items bubble up, only handle the ones of this item
+    let item_ref = use_node_ref();
+    let onfocus = {
+        let (onfocus, item_ref) = (props.context.onfocus.clone(), item_ref.clone());
+        Callback::from(move |evt: FocusEvent| {
+            if is_target(&evt, &item_ref) {
+                onfocus.emit(key.clone());
+            }
+        })
+    };
+
+    let onkeydown = {
+        let (expanded, item_ref) = (expanded.clone(), item_ref.clone());
+        let select = select.clone();
+        Callback::from(move |evt: KeyboardEvent| {
+            if !is_target(&evt, &item_ref) {
+                return;
+            }
+            let Some(item) = item_ref.cast::<Element>() else {
+                return;
+            };
+
+            let navigation = match evt.key().as_str() {
+                "ArrowDown" => Some(RowNavigation::Next),
+                "ArrowUp" => Some(RowNavigation::Previous),
+                "Home" => Some(RowNavigation::First),
+                "End" => Some(RowNavigation::Last),
+                "ArrowRight" if has_children && !*expanded => {
+                    expanded.set(true);
+                    None
+                }
+                "ArrowRight" => Some(RowNavigation::FirstChild),
+                "ArrowLeft" if has_children && *expanded => {
+                    expanded.set(false);
+                    None
+                }
+                "ArrowLeft" => Some(RowNavigation::Parent),
+                // space toggles the selection of checkable items
+                " " if select.is_some() => {
+                    if let Some((state, onselect)) = &select {
+                        onselect.emit(*state != CheckboxState::Checked);
+                    }
+                    None
+                }
+                "Enter" | " " => {
+                    if has_children {
+                        expanded.set(!*expanded);
+                    }
+                    None
+                }
+                _ => return,
+            };
+
+            evt.prevent_default();
+            if let (Some(navigation), Ok(Some(tree))) = (navigation, item.closest("[role=tree]")) {
+                navigation.focus_within(&tree, "li[role=treeitem]", &item);
+            }
+        })
+    };
+
+    html!(
+        <li
+            ref={item_ref}
+            {class}
+            role="treeitem"
+            aria-expanded={has_children.then(|| expanded.to_string())}
+            aria-checked={select.as_ref().map(|(state, _)| match state {
+                CheckboxState::Checked => "true",
+                CheckboxState::Unchecked => "false",
+                CheckboxState::Indeterminate => "mixed",
+            })}
+            aria-level={props.level.to_string()}
+            tabindex={ if tabindex { "0" } else { "-1" } }
+            {onfocus}
+            {onkeydown}
+        >
+            <div class="pf-v5-c-tree-view__content">
+                if let Some((checked, onselect)) = select {
+                    <ItemCheck
+                        {checked}
+                        {onselect}
+                        toggle={has_children.then(|| html!(
+                            <button
+                                class="pf-v5-c-tree-view__node-toggle"
+                                type="button"
+                                tabindex="-1"
+                                aria-label="Toggle"
+                                aria-expanded={expanded.to_string()}
+                                onclick={ontoggle.clone()}
+                            >
+                                { toggle_icon.clone() }
+                            </button>
+                        ))}
+                    >
+                        { node }
+                    </ItemCheck>
+                } else {
+                    <button
+                        class="pf-v5-c-tree-view__node"
+                        type="button"
+                        tabindex="-1"
+                        onclick={ontoggle}
+                    >
+                        <span class="pf-v5-c-tree-view__node-container">
+                            if has_children {
+                                <span class="pf-v5-c-tree-view__node-toggle">
+                                    { toggle_icon }
+                                </span>
+                            }
+                            { node }
+                        </span>
+                    </button>
+                }
+                if let Some(actions) = props.node.actions() {
+                    <div class="pf-v5-c-tree-view__action">{ actions }</div>
+                }
+            </div>
+            if has_children && *expanded {
+                <ul class="pf-v5-c-tree-view__list" role="group">
+                    { render_nodes(&children, props.level + 1, &props.context) }
+                </ul>
+            }
+        </li>
+    )
+}
+
+/// Check if the target of an event is the element of the node reference.
+fn is_target(evt: &Event, node_ref: &NodeRef) -> bool {
+    match (evt.target(), node_ref.get()) {
+        (Some(target), Some(node)) => JsValue::from(target) == JsValue::from(node),
+        _ => false,
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct ItemCheckProperties {
+    children: Html,
+    toggle: Option<Html>,
+    checked: CheckboxState,
+    onselect: Callback<bool>,
+}
+
+/// The node of an item having a checkbox.
+#[function_component(ItemCheck)]
+fn item_check(props: &ItemCheckProperties) -> Html {
+    let id = use_random_id();
+    let node_ref = use_node_ref();
+
+    {
+        let node_ref = node_ref.clone();
+        let checked = props.checked;
+        use_effect(move || {
+            if let Some(elem) = node_ref.cast::<HtmlInputElement>() {
+                elem.set_indeterminate(checked == CheckboxState::Indeterminate)
+            }
+        });
+    }
+
+    let onchange = use_callback(
+        (props.onselect.clone(), node_ref.clone()),
+        |_: Event, (onselect, node_ref)| {
+            if let Some(input) = node_ref.cast::<HtmlInputElement>() {
+                onselect.emit(input.checked());
+            }
+        },
+    );
+
+    html!(
+        <label class="pf-v5-c-tree-view__node" for={*id}>
+            <span class="pf-v5-c-tree-view__node-container">
+                { props.toggle.clone() }
+                <span class="pf-v5-c-tree-view__node-check">
+                    <input
+                        ref={node_ref}
+                        type="checkbox"
+                        tabindex="-1"
+                        id={*id}
+                        checked={props.checked == CheckboxState::Checked}
+                        {onchange}
+                    />
+                </span>
+                { props.children.clone() }
+            </span>
+        </label>
+    )
+}
@@ -0,0 +1,189 @@
+//! Validation
+
+pub mod validators;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct ValidationContext<T> {
+    pub value: T,
+    pub initial: bool,
+}
+
+impl<T> From<T> for ValidationContext<T> {
+    fn from(value: T) -> Self {
+        ValidationContext {
+            value,
+            initial: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum Validator<T, S> {
+    None,
+    Custom(std::rc::Rc<dyn Fn(ValidationContext<T>) -> S>),
+}
+
+impl<T, S> Validator<T, S> {
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    /// Convert into the context and run
+    pub fn run<C>(&self, ctx: C) -> Option<S>
+    where
+        C: Into<ValidationContext<T>>,
+    {
+        self.run_if(|| ctx.into())
+    }
+
+    /// Only convert when necessary, and run.
+    pub fn run_if<F>(&self, f: F) -> Option<S>
+    where
+        F: FnOnce() -> ValidationContext<T>,
+    {
+        match self {
+            Self::Custom(validator) => Some(validator(f())),
+            _ => None,
+        }
+    }
+
+    /// Run with the provided context.
+    pub fn run_ctx(&self, ctx: ValidationContext<T>) -> Option<S> {
+        match self {
+            Self::Custom(validator) => Some(validator(ctx)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, S> Default for Validator<T, S> {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Validators are equal if they are still None. Everything else is a change.
+impl<T, S> PartialEq for Validator<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        matches!((self, other), (Validator::None, Validator::None))
+    }
+}
+
+impl<F, T, S> From<F> for Validator<T, S>
+where
+    F: Fn(ValidationContext<T>) -> S + 'static,
+{
+    fn from(v: F) -> Self {
+        Self::Custom(std::rc::Rc::new(v))
+    }
+}
+
+pub trait IntoValidator<T, S> {
+    fn into_validator(self) -> Validator<T, S>;
+}
+
+impl<F, T, S> IntoValidator<T, S> for F
+where
+    F: Fn(ValidationContext<T>) -> S + 'static,
+{
+    fn into_validator(self) -> Validator<T, S> {
+        Validator::Custom(std::rc::Rc::new(self))
+    }
+}
+
+/// The future of an [`AsyncValidator`].
+pub type ValidationFuture<S> = Pin<Box<dyn Future<Output = S>>>;
+
+type AsyncValidatorFn<T, S> = Rc<dyn Fn(ValidationContext<T>) -> ValidationFuture<S>>;
+
+/// The default delay of an [`AsyncValidator`].
+pub const DEFAULT_VALIDATION_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A validator running asynchronously, e.g. checking a value with a server.
+///
+/// Runs are debounced: a run only starts once the value didn't change for the debounce
+/// duration. Runs which are superseded by a newer value are discarded.
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::prelude::*;
+///
+/// async fn is_taken(name: &str) -> bool {
+///   // ask the server
+///   name == "admin"
+/// }
+///
+/// let validator = AsyncValidator::new(|ctx: ValidationContext<String>| async move {
+///   match is_taken(&ctx.value).await {
+///     true => ValidationResult::error("The name is already taken"),
+///     false => ValidationResult::ok(),
+///   }
+/// });
+/// ```
+pub struct AsyncValidator<T, S> {
+    validator: Option<AsyncValidatorFn<T, S>>,
+    debounce: Duration,
+}
+
+impl<T, S> AsyncValidator<T, S> {
+    pub fn new<F, Fut>(validator: F) -> Self
+    where
+        F: Fn(ValidationContext<T>) -> Fut + 'static,
+        Fut: Future<Output = S> + 'static,
+    {
+        Self {
+            validator: Some(Rc::new(move |ctx| Box::pin(validator(ctx)))),
+            debounce: DEFAULT_VALIDATION_DEBOUNCE,
+        }
+    }
+
+    /// Set the time the value must not change, before running the validator.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn get_debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    pub fn is_custom(&self) -> bool {
+        self.validator.is_some()
+    }
+
+    /// Start running the validator, right away.
+    pub fn run(&self, ctx: ValidationContext<T>) -> Option<ValidationFuture<S>> {
+        self.validator.as_ref().map(|validator| validator(ctx))
+    }
+}
+
+impl<T, S> Clone for AsyncValidator<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            validator: self.validator.clone(),
+            debounce: self.debounce,
+        }
+    }
+}
+
+impl<T, S> Default for AsyncValidator<T, S> {
+    fn default() -> Self {
+        Self {
+            validator: None,
+            debounce: DEFAULT_VALIDATION_DEBOUNCE,
+        }
+    }
+}
+
+/// Validators are equal if they are still None. Everything else is a change.
+impl<T, S> PartialEq for AsyncValidator<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.validator.is_none() && other.validator.is_none()
+    }
+}